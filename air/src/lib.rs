@@ -35,7 +35,7 @@ mod utils;
 // ================================================================================================
 pub use errors::ExecutionOptionsError;
 pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use proof::{ExecutionProof, HashFunction, ProofParams};
 use utils::TransitionConstraintRange;
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
@@ -120,6 +120,17 @@ impl ProvingOptions {
         }
     }
 
+    /// Returns the [ProvingOptions] preset with the specified name, or `None` if there is no
+    /// preset with this name.
+    ///
+    /// See [ProvingOptions::presets()] for the list of available presets.
+    pub fn from_preset_name(name: &str) -> Option<Self> {
+        Self::presets()
+            .iter()
+            .find(|(preset_name, _)| *preset_name == name)
+            .map(|(_, options)| options.clone())
+    }
+
     /// Sets [ExecutionOptions] for this [ProvingOptions].
     ///
     /// This sets the maximum number of cycles a program is allowed to execute as well as
@@ -141,8 +152,58 @@ impl ProvingOptions {
     pub const fn execution_options(&self) -> &ExecutionOptions {
         &self.exec_options
     }
+
+    /// Returns all preset [ProvingOptions] together with their names.
+    ///
+    /// The presets are the ones returned by [ProvingOptions::with_96_bit_security()],
+    /// [ProvingOptions::with_96_bit_security_rpx()], [ProvingOptions::with_128_bit_security()], and
+    /// [ProvingOptions::with_128_bit_security_rpx()]; they are named `<kind>-<security level>`,
+    /// where kind is one of `regular`, `recursive`, or `rpx` (e.g., `recursive-128`).
+    pub fn presets() -> &'static [(&'static str, ProvingOptions)] {
+        &PRESETS
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a preset instance of [ProvingOptions] with default execution options.
+    const fn preset(proof_options: WinterProofOptions, hash_fn: HashFunction) -> Self {
+        Self {
+            exec_options: ExecutionOptions::DEFAULT,
+            proof_options,
+            hash_fn,
+        }
+    }
 }
 
+/// Named [ProvingOptions] presets returned by [ProvingOptions::presets()].
+static PRESETS: [(&str, ProvingOptions); 6] = [
+    (
+        "regular-96",
+        ProvingOptions::preset(ProvingOptions::REGULAR_96_BITS, HashFunction::Blake3_192),
+    ),
+    (
+        "recursive-96",
+        ProvingOptions::preset(ProvingOptions::RECURSIVE_96_BITS, HashFunction::Rpo256),
+    ),
+    (
+        "rpx-96",
+        ProvingOptions::preset(ProvingOptions::RECURSIVE_96_BITS, HashFunction::Rpx256),
+    ),
+    (
+        "regular-128",
+        ProvingOptions::preset(ProvingOptions::REGULAR_128_BITS, HashFunction::Blake3_256),
+    ),
+    (
+        "recursive-128",
+        ProvingOptions::preset(ProvingOptions::RECURSIVE_128_BITS, HashFunction::Rpo256),
+    ),
+    (
+        "rpx-128",
+        ProvingOptions::preset(ProvingOptions::RECURSIVE_128_BITS, HashFunction::Rpx256),
+    ),
+];
+
 impl Default for ProvingOptions {
     fn default() -> Self {
         Self::with_96_bit_security(false)
@@ -172,16 +233,23 @@ pub struct ExecutionOptions {
 
 impl Default for ExecutionOptions {
     fn default() -> Self {
-        ExecutionOptions {
-            max_cycles: u32::MAX,
-            expected_cycles: MIN_TRACE_LEN as u32,
-            enable_tracing: false,
-            enable_debugging: false,
-        }
+        Self::DEFAULT
     }
 }
 
 impl ExecutionOptions {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Default execution options; these impose no limit on the number of cycles (other than
+    /// `u32::MAX`) and disable tracing and debugging.
+    const DEFAULT: Self = ExecutionOptions {
+        max_cycles: u32::MAX,
+        expected_cycles: MIN_TRACE_LEN as u32,
+        enable_tracing: false,
+        enable_debugging: false,
+    };
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -196,7 +264,7 @@ impl ExecutionOptions {
     ) -> Result<Self, ExecutionOptionsError> {
         let max_cycles = max_cycles.unwrap_or(u32::MAX);
         if max_cycles < MIN_TRACE_LEN as u32 {
-            return Err(ExecutionOptionsError::MaxCycleNumTooSmall(expected_cycles));
+            return Err(ExecutionOptionsError::MaxCycleNumTooSmall(max_cycles));
         }
         if max_cycles < expected_cycles {
             return Err(ExecutionOptionsError::ExpectedCyclesTooBig(max_cycles, expected_cycles));
@@ -214,6 +282,29 @@ impl ExecutionOptions {
         })
     }
 
+    /// Sets the maximum number of cycles a program is allowed to execute for.
+    ///
+    /// Execution of a program which exceeds this limit fails with a
+    /// `ExecutionError::CycleLimitExceeded` error.
+    ///
+    /// # Errors
+    /// Returns an error if `max_cycles` is smaller than the minimum trace length, or smaller than
+    /// the expected number of cycles.
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Result<Self, ExecutionOptionsError> {
+        if max_cycles < MIN_TRACE_LEN as u32 {
+            return Err(ExecutionOptionsError::MaxCycleNumTooSmall(max_cycles));
+        }
+        if max_cycles < self.expected_cycles {
+            return Err(ExecutionOptionsError::ExpectedCyclesTooBig(
+                max_cycles,
+                self.expected_cycles,
+            ));
+        }
+
+        self.max_cycles = max_cycles;
+        Ok(self)
+    }
+
     /// Enables execution of the `trace` instructions.
     pub fn with_tracing(mut self) -> Self {
         self.enable_tracing = true;
@@ -258,3 +349,29 @@ impl ExecutionOptions {
         self.enable_debugging
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::ProvingOptions;
+
+    #[test]
+    fn proving_options_presets() {
+        for (name, options) in ProvingOptions::presets() {
+            assert_eq!(ProvingOptions::from_preset_name(name).as_ref(), Some(options));
+        }
+        assert_eq!(ProvingOptions::from_preset_name("unknown"), None);
+
+        let expected = [
+            ("regular-96", ProvingOptions::with_96_bit_security(false)),
+            ("recursive-96", ProvingOptions::with_96_bit_security(true)),
+            ("rpx-96", ProvingOptions::with_96_bit_security_rpx()),
+            ("regular-128", ProvingOptions::with_128_bit_security(false)),
+            ("recursive-128", ProvingOptions::with_128_bit_security(true)),
+            ("rpx-128", ProvingOptions::with_128_bit_security_rpx()),
+        ];
+        assert_eq!(ProvingOptions::presets(), expected.as_slice());
+    }
+}
@@ -29,6 +29,13 @@ impl ExecutionProof {
         Self { proof, hash_fn }
     }
 
+    /// Creates a new instance of [ExecutionProof] from its components.
+    ///
+    /// This is the inverse of [Self::into_parts].
+    pub const fn from_parts(hash_fn: HashFunction, proof: Proof) -> Self {
+        Self::new(proof, hash_fn)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -52,9 +59,32 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns a summary of the STARK protocol parameters used to generate this proof.
+    pub fn parameter_summary(&self) -> ProofParams {
+        let options = self.proof.options();
+        let fri_options = options.to_fri_options();
+        ProofParams {
+            hash_fn: self.hash_fn,
+            security_level: self.security_level(),
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            fri_folding_factor: fri_options.folding_factor(),
+            fri_remainder_max_degree: fri_options.remainder_max_degree(),
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the size of this proof in bytes when serialized via [Self::to_bytes].
+    pub fn size_bytes(&self) -> usize {
+        let mut counter = ByteCounter::default();
+        self.proof.write_into(&mut counter);
+        // one additional byte encodes the hash function
+        counter.0 + 1
+    }
+
     /// Serializes this proof into a vector of bytes.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = self.proof.to_bytes();
@@ -83,6 +113,28 @@ impl ExecutionProof {
     }
 }
 
+// PROOF PARAMETERS
+// ================================================================================================
+
+/// A summary of the STARK protocol parameters used to generate an [ExecutionProof].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofParams {
+    /// The hash function used to generate the proof.
+    pub hash_fn: HashFunction,
+    /// The conjectured security level of the proof in bits.
+    pub security_level: u32,
+    /// The number of queries made to the committed LDE domain.
+    pub num_queries: usize,
+    /// The factor by which the execution trace is extended for the low-degree extension.
+    pub blowup_factor: usize,
+    /// The number of bits of proof-of-work grinding performed by the prover.
+    pub grinding_factor: u32,
+    /// The factor by which the domain is reduced in each FRI layer.
+    pub fri_folding_factor: usize,
+    /// The maximum degree of the FRI remainder polynomial.
+    pub fri_remainder_max_degree: usize,
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
@@ -116,6 +168,31 @@ impl HashFunction {
             HashFunction::Rpx256 => Rpx256::COLLISION_RESISTANCE,
         }
     }
+
+    /// Returns the name of this hash function.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            HashFunction::Blake3_192 => "blake3-192",
+            HashFunction::Blake3_256 => "blake3-256",
+            HashFunction::Rpo256 => "rpo256",
+            HashFunction::Rpx256 => "rpx256",
+        }
+    }
+
+    /// Returns the nominal security level (in bits) of proofs generated using this hash function.
+    ///
+    /// The conjectured security of a proof cannot exceed the collision resistance of the hash
+    /// function used to generate it, so this is the highest security level the hash function
+    /// can be used for; the actual security level of a proof also depends on its parameters.
+    pub const fn nominal_security_bits(&self) -> u32 {
+        self.collision_resistance()
+    }
+
+    /// Returns true if proofs generated using this hash function can be efficiently verified
+    /// within the VM (i.e., are suitable for recursive verification).
+    pub const fn supports_recursion(&self) -> bool {
+        matches!(self, HashFunction::Rpo256)
+    }
 }
 
 impl TryFrom<u8> for HashFunction {
@@ -164,3 +241,70 @@ impl Deserializable for ExecutionProof {
         Ok(ExecutionProof { proof, hash_fn })
     }
 }
+
+/// A [ByteWriter] which only counts the number of bytes written into it.
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl ByteWriter for ByteCounter {
+    fn write_u8(&mut self, _value: u8) {
+        self.0 += 1;
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        self.0 += values.len();
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use winter_air::proof::Proof;
+
+    use super::{ExecutionProof, HashFunction};
+
+    #[test]
+    fn hash_function_properties() {
+        assert_eq!(HashFunction::Blake3_192.name(), "blake3-192");
+        assert_eq!(HashFunction::Blake3_192.nominal_security_bits(), 96);
+        assert!(!HashFunction::Blake3_192.supports_recursion());
+
+        assert_eq!(HashFunction::Blake3_256.name(), "blake3-256");
+        assert_eq!(HashFunction::Blake3_256.nominal_security_bits(), 128);
+        assert!(!HashFunction::Blake3_256.supports_recursion());
+
+        assert_eq!(HashFunction::Rpo256.name(), "rpo256");
+        assert_eq!(HashFunction::Rpo256.nominal_security_bits(), 128);
+        assert!(HashFunction::Rpo256.supports_recursion());
+
+        assert_eq!(HashFunction::Rpx256.name(), "rpx256");
+        assert_eq!(HashFunction::Rpx256.nominal_security_bits(), 128);
+        assert!(!HashFunction::Rpx256.supports_recursion());
+    }
+
+    #[test]
+    fn execution_proof_parts_round_trip() {
+        let proof = ExecutionProof::new(Proof::new_dummy(), HashFunction::Rpo256);
+
+        let (hash_fn, stark_proof) = proof.clone().into_parts();
+        assert_eq!(hash_fn, HashFunction::Rpo256);
+        assert_eq!(ExecutionProof::from_parts(hash_fn, stark_proof), proof);
+    }
+
+    #[test]
+    fn execution_proof_size_and_parameters() {
+        let proof = ExecutionProof::new(Proof::new_dummy(), HashFunction::Rpo256);
+        assert_eq!(proof.size_bytes(), proof.to_bytes().len());
+
+        let params = proof.parameter_summary();
+        assert_eq!(params.hash_fn, HashFunction::Rpo256);
+        assert_eq!(params.security_level, proof.security_level());
+        assert_eq!(params.num_queries, 1);
+        assert_eq!(params.blowup_factor, 2);
+        assert_eq!(params.grinding_factor, 2);
+        assert_eq!(params.fri_folding_factor, 8);
+        assert_eq!(params.fri_remainder_max_degree, 1);
+    }
+}
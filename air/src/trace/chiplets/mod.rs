@@ -111,3 +111,115 @@ pub const MEMORY_D1_COL_IDX: usize = MEMORY_TRACE_OFFSET + memory::D1_COL_IDX;
 /// memory context IDs, addresses, or clock cycles, used to enforce that changes are correctly
 /// constrained.
 pub const MEMORY_D_INV_COL_IDX: usize = MEMORY_TRACE_OFFSET + memory::D_INV_COL_IDX;
+
+// CHIPLET LABELS
+// ================================================================================================
+
+/// Labels of the operations which the chiplets perform in response to requests sent over the
+/// chiplets bus.
+///
+/// The label of an operation is computed as 1 plus the combined chiplet and operation selectors
+/// `[s0, s1, s2, s3]` with the bits reversed, i.e., `8 * s3 + 4 * s2 + 2 * s1 + s0 + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChipletLabel {
+    LinearHash,
+    MpVerify,
+    MrUpdateOld,
+    MrUpdateNew,
+    ReturnHash,
+    ReturnState,
+    BitwiseAnd,
+    BitwiseXor,
+    MemoryRead,
+    MemoryWrite,
+    KernelProcCall,
+}
+
+impl ChipletLabel {
+    /// All chiplet labels.
+    pub const ALL: [Self; 11] = [
+        Self::LinearHash,
+        Self::MpVerify,
+        Self::MrUpdateOld,
+        Self::MrUpdateNew,
+        Self::ReturnHash,
+        Self::ReturnState,
+        Self::BitwiseAnd,
+        Self::BitwiseXor,
+        Self::MemoryRead,
+        Self::MemoryWrite,
+        Self::KernelProcCall,
+    ];
+
+    /// Returns the label of the operation identified by the specified selectors, or `None` if the
+    /// selectors do not identify any operation.
+    pub fn from_selectors(s0: Felt, s1: Felt, s2: Felt, s3: Felt) -> Option<Self> {
+        Self::ALL.into_iter().find(|label| label.selectors() == [s0, s1, s2, s3])
+    }
+
+    /// Returns the selectors `[s0, s1, s2, s3]` identifying the operation with this label.
+    pub const fn selectors(&self) -> [Felt; 4] {
+        match self {
+            Self::LinearHash => [ZERO, ONE, ZERO, ZERO],
+            Self::MpVerify => [ZERO, ONE, ZERO, ONE],
+            Self::MrUpdateOld => [ZERO, ONE, ONE, ZERO],
+            Self::MrUpdateNew => [ZERO, ONE, ONE, ONE],
+            Self::ReturnHash => [ZERO, ZERO, ZERO, ZERO],
+            Self::ReturnState => [ZERO, ZERO, ZERO, ONE],
+            Self::BitwiseAnd => [ONE, ZERO, ZERO, ZERO],
+            Self::BitwiseXor => [ONE, ZERO, ONE, ZERO],
+            Self::MemoryRead => [ONE, ONE, ZERO, ONE],
+            Self::MemoryWrite => [ONE, ONE, ZERO, ZERO],
+            Self::KernelProcCall => [ONE, ONE, ONE, ZERO],
+        }
+    }
+
+    /// Returns the label as a field element.
+    pub fn as_felt(&self) -> Felt {
+        let [s0, s1, s2, s3] = self.selectors();
+        s3.mul_small(1 << 3) + s2.mul_small(1 << 2) + s1.mul_small(2) + s0 + ONE
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::Felt;
+
+    use super::{bitwise, hasher, kernel_rom, memory, ChipletLabel};
+
+    #[test]
+    fn chiplet_labels() {
+        let labels = [
+            (ChipletLabel::LinearHash, Felt::from(hasher::LINEAR_HASH_LABEL)),
+            (ChipletLabel::MpVerify, Felt::from(hasher::MP_VERIFY_LABEL)),
+            (ChipletLabel::MrUpdateOld, Felt::from(hasher::MR_UPDATE_OLD_LABEL)),
+            (ChipletLabel::MrUpdateNew, Felt::from(hasher::MR_UPDATE_NEW_LABEL)),
+            (ChipletLabel::ReturnHash, Felt::from(hasher::RETURN_HASH_LABEL)),
+            (ChipletLabel::ReturnState, Felt::from(hasher::RETURN_STATE_LABEL)),
+            (ChipletLabel::BitwiseAnd, bitwise::BITWISE_AND_LABEL),
+            (ChipletLabel::BitwiseXor, bitwise::BITWISE_XOR_LABEL),
+            (ChipletLabel::MemoryRead, Felt::from(memory::MEMORY_READ_LABEL)),
+            (ChipletLabel::MemoryWrite, Felt::from(memory::MEMORY_WRITE_LABEL)),
+            (ChipletLabel::KernelProcCall, kernel_rom::KERNEL_PROC_LABEL),
+        ];
+        assert_eq!(labels.len(), ChipletLabel::ALL.len());
+
+        for (label, label_felt) in labels {
+            assert_eq!(label.as_felt(), label_felt, "{label:?}");
+
+            // each label constant maps back to the selectors of its operation
+            let label_from_felt =
+                ChipletLabel::ALL.into_iter().find(|label| label.as_felt() == label_felt);
+            assert_eq!(label_from_felt, Some(label));
+            let [s0, s1, s2, s3] = label.selectors();
+            assert_eq!(ChipletLabel::from_selectors(s0, s1, s2, s3), Some(label));
+        }
+
+        // selectors of the hasher chiplet with a selector flag which does not start any operation
+        let [s0, s1, s2, s3] = [Felt::new(0), Felt::new(0), Felt::new(1), Felt::new(0)];
+        assert_eq!(ChipletLabel::from_selectors(s0, s1, s2, s3), None);
+    }
+}
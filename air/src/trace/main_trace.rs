@@ -93,7 +93,12 @@ impl MainTrace {
         self.columns.get_column(DECODER_TRACE_OFFSET)[i]
     }
 
-    /// Helper method to detect change of address.
+    /// Returns `true` if the value in the decoder block address column changes between rows i and
+    /// i + 1.
+    ///
+    /// Note that this refers to the address of the block being executed by the decoder (see
+    /// [Self::addr]), and not to the `addr` column of the kernel ROM chiplet (see
+    /// [Self::chiplet_kernel_addr]).
     pub fn is_addr_change(&self, i: RowIndex) -> bool {
         self.addr(i) != self.addr(i + 1)
     }
@@ -278,6 +283,18 @@ impl MainTrace {
         self.columns.get_column(STACK_TRACE_OFFSET + column)[i]
     }
 
+    /// Returns the word formed by the elements at row i in the four stack trace columns starting
+    /// at `base`, with the element in column `base` first.
+    pub fn stack_word(&self, base: usize, i: RowIndex) -> Word {
+        self.stack_range(base, i)
+    }
+
+    /// Returns the elements at row i in the `N` stack trace columns starting at `base`, with the
+    /// element in column `base` first.
+    pub fn stack_range<const N: usize>(&self, base: usize, i: RowIndex) -> [Felt; N] {
+        core::array::from_fn(|offset| self.stack_element(base + offset, i))
+    }
+
     /// Returns the address of the top element in the stack overflow table at row i.
     pub fn parent_overflow_address(&self, i: RowIndex) -> Felt {
         self.columns.get_column(STACK_TRACE_OFFSET + B1_COL_IDX)[i]
@@ -400,7 +417,22 @@ impl MainTrace {
         self.columns.get_column(MEMORY_V_COL_RANGE.start + 3)[i]
     }
 
+    /// Returns the word formed by the i-th row of the chiplet columns containing the memory value
+    /// elements, starting with the zeroth element.
+    pub fn chiplet_memory_word(&self, i: RowIndex) -> Word {
+        [
+            self.chiplet_memory_value_0(i),
+            self.chiplet_memory_value_1(i),
+            self.chiplet_memory_value_2(i),
+            self.chiplet_memory_value_3(i),
+        ]
+    }
+
     /// Returns `true` if a row is part of the kernel chiplet.
+    ///
+    /// A kernel ROM row is identified by the chiplet selectors `[s0, s1, s2, s3]` being set to
+    /// `[1, 1, 1, 0]`. The value of `s3` matters because the padding rows following the kernel
+    /// ROM rows at the end of the chiplets trace have all four selectors set to `1`.
     pub fn is_kernel_row(&self, i: RowIndex) -> bool {
         self.chiplet_selector_0(i) == ONE
             && self.chiplet_selector_1(i) == ONE
@@ -66,7 +66,8 @@ impl Assembler {
                 block_builder.mast_forest_builder_mut(),
             )?;
             // Note: it's ok to `unwrap()` here since `proc_body_id` was returned from
-            // `mast_forest_builder`
+            // `mast_forest_builder`. If the procedure is only known by its MAST root, this is an
+            // external node, whose digest is the MAST root of the referenced procedure.
             block_builder
                 .mast_forest_builder()
                 .get_mast_node(proc_body_id)
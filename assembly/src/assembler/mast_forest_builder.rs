@@ -433,7 +433,15 @@ impl MastForestBuilder {
     }
 
     /// Adds an external node to the forest, and returns the [`MastNodeId`] associated with it.
+    ///
+    /// If a procedure with the specified MAST root has already been added to the forest, the ID of
+    /// the body of that procedure is returned instead, so that an external reference never
+    /// shadows a procedure whose MAST is available.
     pub fn ensure_external(&mut self, mast_root: RpoDigest) -> Result<MastNodeId, AssemblyError> {
+        if let Some(procedure) = self.find_procedure_by_mast_root(&mast_root) {
+            return Ok(procedure.body_node_id());
+        }
+
         self.ensure_node(MastNode::new_external(mast_root))
     }
 
@@ -5,12 +5,15 @@ use vm_core::{
     assert_matches,
     crypto::hash::RpoDigest,
     mast::{MastForest, MastNode},
-    Program,
+    Decorator, Program,
 };
 
-use super::{Assembler, Operation};
+use super::{Assembler, GlobalProcedureIndex, ModuleIndex, Operation, ProcedureContext};
 use crate::{
-    assembler::mast_forest_builder::MastForestBuilder, diagnostics::Report, testing::TestContext,
+    assembler::mast_forest_builder::MastForestBuilder,
+    ast::{ProcedureIndex, Visibility},
+    diagnostics::Report,
+    testing::TestContext,
 };
 
 // TESTS
@@ -291,6 +294,42 @@ fn duplicate_nodes() {
     assert_eq!(expected_program, program);
 }
 
+/// Ensures that an external reference to the MAST root of a procedure already in the forest
+/// resolves to the body of that procedure, rather than to a new external node.
+#[test]
+fn ensure_external_of_known_procedure() {
+    let context = TestContext::new();
+    let mut mast_forest_builder = MastForestBuilder::default();
+
+    // the decorator ensures that an external node with the same MAST root would have a different
+    // fingerprint than the procedure body
+    let decorator_id = mast_forest_builder.ensure_decorator(Decorator::Trace(0)).unwrap();
+    let body_node_id = mast_forest_builder
+        .ensure_block(vec![Operation::Add], Some(vec![(0, decorator_id)]))
+        .unwrap();
+    let mast_root = mast_forest_builder[body_node_id].digest();
+
+    let gid = GlobalProcedureIndex {
+        module: ModuleIndex::new(0),
+        index: ProcedureIndex::new(0),
+    };
+    let procedure = ProcedureContext::new(
+        gid,
+        "test::foo::bar".parse().unwrap(),
+        Visibility::Public,
+        false,
+        context.source_manager(),
+    )
+    .into_procedure(mast_root, body_node_id);
+    mast_forest_builder.insert_procedure(gid, procedure).unwrap();
+
+    assert_eq!(mast_forest_builder.ensure_external(mast_root).unwrap(), body_node_id);
+
+    // unknown MAST roots are still wrapped in an external node
+    let external_node_id = mast_forest_builder.ensure_external(RpoDigest::default()).unwrap();
+    assert!(mast_forest_builder[external_node_id].is_external());
+}
+
 #[test]
 fn explicit_fully_qualified_procedure_references() -> Result<(), Report> {
     const BAR_NAME: &str = "foo::bar";
@@ -323,8 +323,33 @@ impl FromStr for ProcedureName {
                 }
             },
             Some((_, c)) if c.is_ascii_uppercase() => Err(IdentError::Casing(CaseKindError::Snake)),
-            Some(_) => Err(IdentError::InvalidChars { ident: s.into() }),
+            Some(_) => Err(IdentError::InvalidStart),
         }?;
         Ok(Self(Ident::new_unchecked(Span::unknown(raw))))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::assert_matches;
+
+    use super::{CaseKindError, IdentError, ProcedureName};
+
+    #[test]
+    fn procedure_name_validation() {
+        for name in ["foo", "foo_bar1", "_foo", "$foo", "\"foo::bar<baz>\""] {
+            assert!(ProcedureName::new(name).is_ok(), "{name}");
+        }
+
+        assert_matches!(ProcedureName::new(""), Err(IdentError::Empty));
+        assert_matches!(ProcedureName::new("1foo"), Err(IdentError::InvalidStart));
+        assert_matches!(ProcedureName::new("#foo"), Err(IdentError::InvalidStart));
+        assert_matches!(ProcedureName::new("Foo"), Err(IdentError::Casing(CaseKindError::Snake)));
+        assert_matches!(ProcedureName::new("foo-bar"), Err(IdentError::InvalidChars { .. }));
+        assert_matches!(ProcedureName::new("\"foo bar\""), Err(IdentError::InvalidChars { .. }));
+        assert_matches!(ProcedureName::new("\"foo"), Err(IdentError::InvalidChars { .. }));
+    }
+}
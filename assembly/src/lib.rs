@@ -35,6 +35,8 @@ mod tests;
 pub use vm_core::mast;
 pub use vm_core::utils;
 
+#[cfg(feature = "std")]
+pub use self::library::WriteOptions;
 pub use self::{
     assembler::Assembler,
     compile::{Compile, Options as CompileOptions},
@@ -44,8 +46,8 @@ pub use self::{
     },
     errors::AssemblyError,
     library::{
-        KernelLibrary, Library, LibraryError, LibraryNamespace, LibraryPath, LibraryPathComponent,
-        PathError, Version, VersionError,
+        KernelLibrary, Library, LibraryDiff, LibraryError, LibraryNamespace, LibraryPath,
+        LibraryPathComponent, LibraryStatistics, PathError, Version, VersionError,
     },
     parser::ModuleParser,
 };
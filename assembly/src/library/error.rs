@@ -1,9 +1,12 @@
 use vm_core::errors::KernelError;
 
+use super::LibraryNamespaceError;
 use crate::{ast::QualifiedProcedureName, diagnostics::Diagnostic};
 
 #[derive(Debug, thiserror::Error, Diagnostic)]
 pub enum LibraryError {
+    #[error("duplicate export in library: {procedure_path}")]
+    DuplicateExport { procedure_path: QualifiedProcedureName },
     #[error("kernel library must contain at least one exported procedure")]
     #[diagnostic()]
     EmptyKernel,
@@ -11,6 +14,8 @@ pub enum LibraryError {
     InvalidKernelExport { procedure_path: QualifiedProcedureName },
     #[error(transparent)]
     Kernel(#[from] KernelError),
+    #[error(transparent)]
+    Namespace(#[from] LibraryNamespaceError),
     #[error("invalid export: no procedure root for {procedure_path} procedure")]
     NoProcedureRootForExport { procedure_path: QualifiedProcedureName },
 }
@@ -8,9 +8,9 @@ use alloc::{
 use vm_core::{
     crypto::hash::RpoDigest,
     debuginfo::Span,
-    mast::{MastForest, MastNodeId},
+    mast::{MastForest, MastNode, MastNodeId},
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    Kernel,
+    Decorator, Kernel,
 };
 
 use crate::ast::{Ident, ProcedureName, QualifiedProcedureName};
@@ -23,6 +23,8 @@ mod version;
 
 pub use module::{ModuleInfo, ProcedureInfo};
 
+#[cfg(feature = "std")]
+pub use self::use_std_library::WriteOptions;
 pub use self::{
     error::LibraryError,
     namespace::{LibraryNamespace, LibraryNamespaceError},
@@ -155,6 +157,189 @@ impl Library {
 
         modules_by_path.into_values()
     }
+
+    /// Consumes the library, returning its module infos keyed by the path of each module.
+    pub fn into_module_map(self) -> BTreeMap<LibraryPath, ModuleInfo> {
+        let mut modules_by_path: BTreeMap<LibraryPath, ModuleInfo> = BTreeMap::new();
+
+        for (proc_name, proc_root_node_id) in self.exports {
+            let QualifiedProcedureName { module, name, .. } = proc_name;
+            let proc_digest = self.mast_forest[proc_root_node_id].digest();
+            modules_by_path
+                .entry(module)
+                .or_insert_with_key(|path| ModuleInfo::new(path.clone()))
+                .add_procedure(name, proc_digest);
+        }
+
+        modules_by_path
+    }
+}
+
+/// Statistics
+impl Library {
+    /// Returns a summary of the contents of this library.
+    ///
+    /// See [LibraryStatistics] for details.
+    pub fn statistics(&self) -> LibraryStatistics {
+        let num_modules = self
+            .exports
+            .keys()
+            .map(|proc_name| &proc_name.module)
+            .collect::<BTreeSet<_>>()
+            .len();
+        let num_external_dependencies = self
+            .mast_forest
+            .nodes()
+            .iter()
+            .filter(|node| node.is_external())
+            .map(|node| node.digest())
+            .collect::<BTreeSet<_>>()
+            .len();
+        let has_debug_info = self
+            .mast_forest
+            .decorators()
+            .iter()
+            .any(|decorator| matches!(decorator, Decorator::AsmOp(_)));
+
+        LibraryStatistics {
+            num_modules,
+            num_exports: self.num_exports(),
+            num_nodes: self.mast_forest.num_nodes() as usize,
+            num_external_dependencies,
+            has_debug_info,
+        }
+    }
+}
+
+/// Comparison
+impl Library {
+    /// Compares the exports of this library against the exports of `other`, treating this library
+    /// as the old version and `other` as the new version.
+    ///
+    /// Exports are matched by their fully-qualified procedure name. See [LibraryDiff] for details.
+    pub fn diff(&self, other: &Library) -> LibraryDiff {
+        let mut diff = LibraryDiff::default();
+
+        for (proc_name, &node_id) in self.exports.iter() {
+            let old_digest = self.mast_forest[node_id].digest();
+            match other.exports.get(proc_name) {
+                Some(&other_node_id) => {
+                    let new_digest = other.mast_forest[other_node_id].digest();
+                    if old_digest != new_digest {
+                        diff.changed.push((proc_name.clone(), old_digest, new_digest));
+                    }
+                },
+                None => diff.removed.push(proc_name.clone()),
+            }
+        }
+
+        diff.added = other
+            .exports
+            .keys()
+            .filter(|proc_name| !self.exports.contains_key(proc_name))
+            .cloned()
+            .collect();
+
+        diff
+    }
+}
+
+/// Renaming
+impl Library {
+    /// Moves all exports of this library in the `from` namespace into the `to` namespace.
+    ///
+    /// For example, renaming `old` to `new` turns the export `old::math::u64::add` into
+    /// `new::math::u64::add`; exports in other namespaces are left unchanged. Since procedure
+    /// names do not affect MAST hashing, the MAST forest and the content hash of the library
+    /// remain the same.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `to` is a reserved namespace.
+    /// - A renamed export would have the same fully-qualified name as an export which is not
+    ///   renamed.
+    ///
+    /// The library is left unchanged if an error is returned.
+    pub fn rename_namespace(
+        &mut self,
+        from: &LibraryNamespace,
+        to: LibraryNamespace,
+    ) -> Result<(), LibraryError> {
+        to.validate_user_namespace()?;
+
+        let renamed: Vec<_> = self
+            .exports
+            .iter()
+            .map(|(proc_name, node_id)| {
+                let mut proc_name = proc_name.clone();
+                if proc_name.module.namespace() == from {
+                    proc_name.module = proc_name.module.with_namespace(to.clone());
+                }
+                (proc_name, *node_id)
+            })
+            .collect();
+
+        let mut exports = BTreeMap::new();
+        for (proc_name, node_id) in renamed {
+            if exports.contains_key(&proc_name) {
+                return Err(LibraryError::DuplicateExport { procedure_path: proc_name });
+            }
+            exports.insert(proc_name, node_id);
+        }
+        self.exports = exports;
+
+        Ok(())
+    }
+}
+
+/// Analysis
+impl Library {
+    /// Returns the exports of this library which are invoked as callees by other procedures of
+    /// this library, in the order of their fully-qualified names.
+    ///
+    /// An export which is only used internally may have been declared with `export` by mistake
+    /// where `proc` was intended, so this can be used by linters to flag such procedures.
+    ///
+    /// Since this is determined from the MAST of the library, only invocations which remain
+    /// visible as procedure boundaries are taken into account: `call` and `syscall` targets, and
+    /// procedures referenced by their MAST root. Invocations via `exec` are inlined into the
+    /// caller and are not detected, since an inlined procedure body cannot be distinguished from
+    /// identical code which happens to share its MAST node after deduplication. The invocations
+    /// are collected from all procedures reachable from the exports, so an export which is only
+    /// invoked from a non-exported procedure is also reported, as long as that procedure is
+    /// itself invoked by an export.
+    pub fn internal_exports(&self) -> Vec<QualifiedProcedureName> {
+        let mut callees = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        let mut to_visit: Vec<MastNodeId> = self.exports.values().copied().collect();
+        while let Some(node_id) = to_visit.pop() {
+            if !visited.insert(node_id) {
+                continue;
+            }
+
+            match &self.mast_forest[node_id] {
+                MastNode::Call(call_node) => {
+                    callees.insert(call_node.callee());
+                },
+                MastNode::External(external_node) => {
+                    if let Some(callee) =
+                        self.mast_forest.find_procedure_root(external_node.digest())
+                    {
+                        callees.insert(callee);
+                        to_visit.push(callee);
+                    }
+                },
+                _ => (),
+            }
+            to_visit.extend(self.mast_forest[node_id].children());
+        }
+
+        self.exports
+            .iter()
+            .filter(|(_, node_id)| callees.contains(*node_id))
+            .map(|(proc_name, _)| proc_name.clone())
+            .collect()
+    }
 }
 
 impl Serializable for Library {
@@ -174,7 +359,12 @@ impl Serializable for Library {
 
 impl Deserializable for Library {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let mast_forest = Arc::new(MastForest::read_from(source)?);
+        let mast_forest = MastForest::read_from(source)?;
+        // a cyclic forest would only be detected during execution
+        mast_forest.validate_acyclic().map_err(|err| {
+            DeserializationError::InvalidValue(format!("malformed library MAST forest: {err}"))
+        })?;
+        let mast_forest = Arc::new(mast_forest);
 
         let num_exports = source.read_usize()?;
         let mut exports = BTreeMap::new();
@@ -190,9 +380,11 @@ impl Deserializable for Library {
             exports.insert(proc_name, proc_node_id);
         }
 
-        let digest = compute_content_hash(&exports, &mast_forest);
-
-        Ok(Self { digest, exports, mast_forest })
+        // exports which are not procedure roots would break lookups by procedure digest (e.g.,
+        // of kernel procedures)
+        Self::new(mast_forest, exports).map_err(|err| {
+            DeserializationError::InvalidValue(format!("malformed library exports: {err}"))
+        })
     }
 }
 
@@ -217,6 +409,23 @@ mod use_std_library {
     use super::*;
     use crate::Assembler;
 
+    /// Options which control how a library is written to disk.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct WriteOptions {
+        /// If false, writing fails with [`io::ErrorKind::AlreadyExists`] when the target file
+        /// already exists.
+        pub overwrite: bool,
+        /// If true, any missing parent directories of the target file are created.
+        pub create_dirs: bool,
+    }
+
+    impl Default for WriteOptions {
+        /// Overwrites existing files and creates missing parent directories.
+        fn default() -> Self {
+            Self { overwrite: true, create_dirs: true }
+        }
+    }
+
     impl Library {
         /// File extension for the Assembly Library.
         pub const LIBRARY_EXTENSION: &'static str = "masl";
@@ -227,17 +436,35 @@ mod use_std_library {
         /// specific requirement that the extension be set, or the same as
         /// [`Self::LIBRARY_EXTENSION`].
         pub fn write_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+            self.write_to_file_with_options(path, WriteOptions::default())
+        }
+
+        /// Write the library to a target file, as controlled by the provided [WriteOptions].
+        ///
+        /// Returns an error of kind [`io::ErrorKind::AlreadyExists`] if the target file exists
+        /// and `options.overwrite` is false.
+        pub fn write_to_file_with_options(
+            &self,
+            path: impl AsRef<Path>,
+            options: WriteOptions,
+        ) -> io::Result<()> {
             let path = path.as_ref();
 
-            if let Some(dir) = path.parent() {
-                fs::create_dir_all(dir)?;
+            if options.create_dirs {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
             }
 
             // NOTE: We catch panics due to i/o errors here due to the fact that the ByteWriter
             // trait does not provide fallible APIs, so WriteAdapter will panic if the underlying
             // writes fail. This needs to be addressed in winterfell at some point
             std::panic::catch_unwind(|| {
-                let mut file = fs::File::create(path)?;
+                let mut file = if options.overwrite {
+                    fs::File::create(path)?
+                } else {
+                    fs::OpenOptions::new().write(true).create_new(true).open(path)?
+                };
                 self.write_into(&mut file);
                 Ok(())
             })
@@ -309,6 +536,51 @@ mod use_std_library {
     }
 }
 
+// LIBRARY DIFF
+// ================================================================================================
+
+/// The difference between the exports of two versions of a [Library], as computed by
+/// [Library::diff].
+///
+/// All lists are sorted by fully-qualified procedure name.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LibraryDiff {
+    /// Procedures which are exported only by the new version of the library.
+    pub added: Vec<QualifiedProcedureName>,
+    /// Procedures which are exported only by the old version of the library.
+    pub removed: Vec<QualifiedProcedureName>,
+    /// Procedures which are exported by both versions of the library, but whose MAST roots differ,
+    /// together with their old and new MAST roots, respectively.
+    pub changed: Vec<(QualifiedProcedureName, RpoDigest, RpoDigest)>,
+}
+
+impl LibraryDiff {
+    /// Returns true if the exports of the compared libraries are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+// LIBRARY STATISTICS
+// ================================================================================================
+
+/// A summary of the contents of a [Library], as computed by [Library::statistics].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LibraryStatistics {
+    /// The number of modules which export at least one procedure.
+    pub num_modules: usize,
+    /// The number of exported procedures.
+    pub num_exports: usize,
+    /// The number of nodes in the underlying MAST forest.
+    pub num_nodes: usize,
+    /// The number of distinct procedures, identified by MAST root, which are referenced by the
+    /// library but defined outside of it.
+    pub num_external_dependencies: usize,
+    /// True if the underlying MAST forest contains source-level debug information, i.e., `AsmOp`
+    /// decorators.
+    pub has_debug_info: bool,
+}
+
 // KERNEL LIBRARY
 // ================================================================================================
 
@@ -343,6 +615,21 @@ impl KernelLibrary {
         self.library.mast_forest()
     }
 
+    /// Returns the IDs of the MAST nodes at the roots of the kernel procedures, in the same order
+    /// as the procedure hashes returned by [Kernel::proc_hashes].
+    pub fn kernel_procedure_ids(&self) -> Vec<MastNodeId> {
+        let mast_forest = self.mast_forest();
+        self.kernel
+            .proc_hashes()
+            .iter()
+            .map(|&proc_hash| {
+                mast_forest
+                    .find_procedure_root(proc_hash)
+                    .expect("kernel procedure is a root of the kernel library's MAST forest")
+            })
+            .collect()
+    }
+
     /// Destructures this kernel library into individual parts.
     pub fn into_parts(self) -> (Kernel, ModuleInfo, Arc<MastForest>) {
         (self.kernel, self.kernel_info, self.library.mast_forest)
@@ -29,6 +29,11 @@ pub enum LibraryNamespaceError {
     )]
     #[diagnostic()]
     InvalidStart,
+    #[error("invalid library namespace name: '{0}' is reserved")]
+    #[diagnostic(help(
+        "reserved namespaces start with '#' and cannot be used for user libraries"
+    ))]
+    Reserved(Arc<str>),
 }
 
 /// Represents the root component of a library path, akin to a Rust crate name
@@ -65,12 +70,33 @@ impl LibraryNamespace {
 // ------------------------------------------------------------------------------------------------
 /// Constructors
 impl LibraryNamespace {
-    /// Construct a new [LibraryNamespace] from `source`
+    /// Construct a new user-defined [LibraryNamespace] from `source`.
+    ///
+    /// This is the validating constructor that all user-facing conversions (i.e. [FromStr] and
+    /// `TryFrom<Ident>`) go through.
+    ///
+    /// # Errors
+    /// Returns an error if `source` is not a valid namespace, or if it is a reserved namespace
+    /// (e.g. `#sys`).
     pub fn new<S>(source: S) -> Result<Self, LibraryNamespaceError>
     where
         S: AsRef<str>,
     {
-        source.as_ref().parse()
+        let namespace = Self::parse_any(source.as_ref())?;
+        namespace.validate_user_namespace()?;
+        Ok(namespace)
+    }
+
+    /// Construct a new user-defined [LibraryNamespace] from `name`.
+    ///
+    /// This is equivalent to [LibraryNamespace::new].
+    pub fn user(name: &str) -> Result<Self, LibraryNamespaceError> {
+        Self::new(name)
+    }
+
+    /// Returns the reserved namespace for kernel modules.
+    pub const fn kernel() -> Self {
+        Self::Kernel
     }
 
     /// Construct a new [LibraryNamespace] from a previously-validated [Ident].
@@ -87,10 +113,29 @@ impl LibraryNamespace {
 
     /// Parse a [LibraryNamespace] by taking the prefix of the given path string, and returning
     /// the namespace and remaining string if successful.
+    ///
+    /// Unlike [LibraryNamespace::new], this accepts reserved namespaces, since these are valid
+    /// prefixes of fully-qualified paths (e.g. `#exec::$main`).
     pub fn strip_path_prefix(path: &str) -> Result<(Self, &str), LibraryNamespaceError> {
         match path.split_once("::") {
-            Some((ns, rest)) => ns.parse().map(|ns| (ns, rest)),
-            None => path.parse().map(|ns| (ns, "")),
+            Some((ns, rest)) => Self::parse_any(ns).map(|ns| (ns, rest)),
+            None => Self::parse_any(path).map(|ns| (ns, "")),
+        }
+    }
+
+    /// Parse a [LibraryNamespace] from `source`, accepting reserved namespaces.
+    ///
+    /// This is used when parsing fully-qualified paths and deserializing libraries, where reserved
+    /// namespaces such as `#sys` and `#exec` are expected.
+    pub(crate) fn parse_any(source: &str) -> Result<Self, LibraryNamespaceError> {
+        match source {
+            Self::KERNEL_PATH => Ok(Self::Kernel),
+            Self::EXEC_PATH => Ok(Self::Exec),
+            Self::ANON_PATH => Ok(Self::Anon),
+            other => {
+                Self::validate(other)?;
+                Ok(Self::User(Arc::from(other.to_string().into_boxed_str())))
+            },
         }
     }
 }
@@ -99,10 +144,23 @@ impl LibraryNamespace {
 /// Public accessors
 impl LibraryNamespace {
     /// Returns true if this namespace is a reserved namespace.
+    ///
+    /// Reserved namespaces (e.g., `#sys` for kernels) start with `#`, and are used internally by
+    /// the assembler. They cannot be used as the namespace of a user library.
     pub fn is_reserved(&self) -> bool {
         !matches!(self, Self::User(_))
     }
 
+    /// Returns an error if this namespace is reserved, and thus cannot be used as the namespace of
+    /// a user library.
+    pub fn validate_user_namespace(&self) -> Result<(), LibraryNamespaceError> {
+        if self.is_reserved() {
+            Err(LibraryNamespaceError::Reserved(self.as_refcounted_str()))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Checks if `source` is a valid [LibraryNamespace]
     pub fn validate(source: impl AsRef<str>) -> Result<(), LibraryNamespaceError> {
         let source = source.as_ref();
@@ -181,27 +239,14 @@ impl FromStr for LibraryNamespace {
     type Err = LibraryNamespaceError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            Self::KERNEL_PATH => Ok(Self::Kernel),
-            Self::EXEC_PATH => Ok(Self::Exec),
-            Self::ANON_PATH => Ok(Self::Anon),
-            other => {
-                Self::validate(other)?;
-                Ok(Self::User(Arc::from(other.to_string().into_boxed_str())))
-            },
-        }
+        Self::new(s)
     }
 }
 
 impl TryFrom<Ident> for LibraryNamespace {
     type Error = LibraryNamespaceError;
     fn try_from(ident: Ident) -> Result<Self, Self::Error> {
-        match ident.as_str() {
-            Self::KERNEL_PATH => Ok(Self::Kernel),
-            Self::EXEC_PATH => Ok(Self::Exec),
-            Self::ANON_PATH => Ok(Self::Anon),
-            other => Self::new(other),
-        }
+        Self::new(ident.as_str())
     }
 }
 
@@ -225,6 +270,76 @@ impl Deserializable for LibraryNamespace {
         let name = source.read_slice(num_bytes)?;
         let name =
             str::from_utf8(name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
-        Self::new(name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))
+        Self::parse_any(name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use super::{LibraryNamespace, LibraryNamespaceError};
+    use crate::{ast::Ident, Deserializable, Serializable, Span};
+
+    #[test]
+    fn user_namespace() {
+        for name in ["std", "miden_lib", "a1"] {
+            let namespace = LibraryNamespace::user(name).unwrap();
+            assert_eq!(namespace, LibraryNamespace::User(Arc::from(name)));
+            assert!(!namespace.is_reserved());
+        }
+
+        for name in [
+            LibraryNamespace::KERNEL_PATH,
+            LibraryNamespace::EXEC_PATH,
+            LibraryNamespace::ANON_PATH,
+        ] {
+            assert_eq!(
+                LibraryNamespace::user(name),
+                Err(LibraryNamespaceError::Reserved(Arc::from(name)))
+            );
+        }
+
+        assert_eq!(LibraryNamespace::user(""), Err(LibraryNamespaceError::Empty));
+        assert_eq!(LibraryNamespace::user("std::math"), Err(LibraryNamespaceError::InvalidChars));
+        assert_eq!(LibraryNamespace::user("::std"), Err(LibraryNamespaceError::InvalidStart));
+        assert_eq!(LibraryNamespace::user("#std"), Err(LibraryNamespaceError::InvalidStart));
+    }
+
+    #[test]
+    fn kernel_namespace() {
+        let namespace = LibraryNamespace::kernel();
+        assert_eq!(namespace, LibraryNamespace::parse_any(LibraryNamespace::KERNEL_PATH).unwrap());
+        assert!(namespace.is_reserved());
+    }
+
+    #[test]
+    fn reserved_namespace_rejected_by_user_constructors() {
+        for (name, namespace) in [
+            (LibraryNamespace::KERNEL_PATH, LibraryNamespace::Kernel),
+            (LibraryNamespace::EXEC_PATH, LibraryNamespace::Exec),
+            (LibraryNamespace::ANON_PATH, LibraryNamespace::Anon),
+        ] {
+            let expected = Err(LibraryNamespaceError::Reserved(Arc::from(name)));
+            assert_eq!(LibraryNamespace::new(name), expected);
+            assert_eq!(name.parse::<LibraryNamespace>(), expected);
+            let ident = Ident::new_unchecked(Span::unknown(Arc::from(name)));
+            assert_eq!(LibraryNamespace::try_from(ident), expected);
+
+            // reserved namespaces are still accepted as the prefix of a path, and when
+            // deserializing a previously-constructed namespace
+            assert_eq!(LibraryNamespace::strip_path_prefix(name), Ok((namespace.clone(), "")));
+            let bytes = namespace.to_bytes();
+            assert_eq!(LibraryNamespace::read_from_bytes(&bytes).unwrap(), namespace);
+        }
+
+        let std = LibraryNamespace::User(Arc::from("std"));
+        assert_eq!(LibraryNamespace::new("std"), Ok(std.clone()));
+        assert_eq!("std".parse::<LibraryNamespace>(), Ok(std.clone()));
+        let ident = Ident::new_unchecked(Span::unknown(Arc::from("std")));
+        assert_eq!(LibraryNamespace::try_from(ident), Ok(std));
     }
 }
@@ -150,10 +150,9 @@ impl LibraryPath {
 
         // Parse namespace
         let mut parts = source.split("::");
-        let ns = parts
-            .next()
-            .ok_or(PathError::Empty)
-            .and_then(|part| LibraryNamespace::new(part).map_err(PathError::InvalidNamespace))?;
+        let ns = parts.next().ok_or(PathError::Empty).and_then(|part| {
+            LibraryNamespace::parse_any(part).map_err(PathError::InvalidNamespace)
+        })?;
 
         // Parse components
         let mut components = Components::default();
@@ -361,6 +360,30 @@ impl LibraryPath {
         Ok(Self::make(ns, components))
     }
 
+    /// Returns a new path, representing the current one with its namespace removed if it is
+    /// `prefix`, so that the first non-namespace component becomes the new namespace.
+    ///
+    /// Returns `None` if the namespace of this path is not `prefix`, if the path has no other
+    /// components, or if its first non-namespace component is not a valid [LibraryNamespace].
+    pub fn strip_prefix(&self, prefix: &LibraryNamespace) -> Option<Self> {
+        if &self.inner.ns != prefix {
+            return None;
+        }
+
+        let (first, rest) = self.inner.components.split_first()?;
+        let ns = LibraryNamespace::try_from(first.clone()).ok()?;
+        Some(Self::make(ns, rest.iter().cloned().collect()))
+    }
+
+    /// Returns a new path, representing the current one with its namespace replaced by `ns`.
+    ///
+    /// For example, `old::math::u64` becomes `new::math::u64` when `ns` is `new`.
+    pub fn with_namespace(&self, ns: LibraryNamespace) -> Self {
+        let mut path = self.clone();
+        path.set_namespace(ns);
+        path
+    }
+
     /// Pops the last non-namespace component in this path
     pub fn pop(&mut self) -> Option<Ident> {
         let inner = Arc::make_mut(&mut self.inner);
@@ -535,9 +558,13 @@ fn validate_component(component: &str) -> Result<(), PathError> {
 /// Tests
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use vm_core::assert_matches;
 
-    use super::{super::LibraryNamespaceError, IdentError, LibraryPath, PathError};
+    use super::{
+        super::LibraryNamespaceError, IdentError, LibraryNamespace, LibraryPath, PathError,
+    };
 
     #[test]
     fn new_path() {
@@ -557,6 +584,19 @@ mod tests {
         assert_eq!(path.num_components(), 3);
     }
 
+    #[test]
+    fn path_display_round_trip() {
+        for source in ["foo", "#sys", "#sys::bar", "#exec::bar::baz", "std::crypto::hashes::rpo"] {
+            let path = source.parse::<LibraryPath>().unwrap();
+            assert_eq!(path.to_string(), source);
+            assert_eq!(path.to_string().parse::<LibraryPath>().unwrap(), path);
+        }
+
+        let path = LibraryPath::new("#sys::bar").unwrap();
+        assert!(path.is_kernel_path());
+        assert_eq!(path.to_string().parse::<LibraryPath>().unwrap().num_components(), 2);
+    }
+
     #[test]
     fn new_path_fail() {
         let path = LibraryPath::new("");
@@ -586,4 +626,31 @@ mod tests {
             Err(PathError::InvalidNamespace(LibraryNamespaceError::InvalidStart))
         );
     }
+
+    #[test]
+    fn path_renamespacing() {
+        let old_ns = LibraryNamespace::new("old").unwrap();
+        let new_ns = LibraryNamespace::new("new").unwrap();
+        let path = LibraryPath::new("old::math::u64").unwrap();
+
+        // a matching prefix is stripped, and the first component becomes the namespace
+        let stripped = path.strip_prefix(&old_ns).unwrap();
+        assert_eq!(stripped.to_string(), "math::u64");
+        assert_eq!(stripped.namespace().as_str(), "math");
+
+        // a non-matching prefix is not stripped
+        assert_eq!(path.strip_prefix(&new_ns), None);
+        assert_eq!(LibraryPath::new("old").unwrap().strip_prefix(&old_ns), None);
+
+        assert_eq!(path.with_namespace(new_ns.clone()).to_string(), "new::math::u64");
+        assert_eq!(path.to_string(), "old::math::u64");
+
+        // kernel paths can be moved in and out of the kernel namespace
+        let kernel_path = path.with_namespace(LibraryNamespace::Kernel);
+        assert!(kernel_path.is_kernel_path());
+        assert_eq!(kernel_path.to_string(), "#sys::math::u64");
+        assert_eq!(kernel_path.strip_prefix(&old_ns), None);
+        assert_eq!(kernel_path.strip_prefix(&LibraryNamespace::Kernel).unwrap(), stripped);
+        assert_eq!(kernel_path.with_namespace(new_ns).to_string(), "new::math::u64");
+    }
 }
@@ -1,6 +1,11 @@
-use alloc::string::ToString;
+use alloc::{format, string::ToString};
 use core::str::FromStr;
 
+use vm_core::{
+    mast::{LoopNode, MastForestError, MastNode},
+    Operation,
+};
+
 use super::*;
 use crate::{
     ast::{Module, ModuleKind, ProcedureName},
@@ -171,6 +176,198 @@ fn library_procedure_collision() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn library_internal_exports() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let foo = r#"
+        proc.helper
+            call.d
+        end
+
+        export.a
+            push.1
+            call.b
+            call.helper
+        end
+
+        export.b
+            if.true
+                push.2 mul
+            else
+                push.3 add
+            end
+        end
+
+        # the body of c is deduplicated with the `if.true` branch of b, but c is not invoked
+        export.c
+            push.2 mul
+        end
+
+        # invoked only from a non-exported procedure which is invoked by an export
+        export.d
+            push.4 add
+        end
+
+        # exec is inlined into the caller and is not detected
+        export.e
+            push.5
+            exec.b
+        end
+    "#;
+    let foo = parse_module!(&context, "lib1::foo", foo);
+    let lib = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    // make sure the deduplication the test relies on is in effect
+    let c = QualifiedProcedureName::from_str("lib1::foo::c").unwrap();
+    let b = QualifiedProcedureName::from_str("lib1::foo::b").unwrap();
+    let b_children = lib.mast_forest()[lib.get_export_node_id(&b)].children();
+    assert!(b_children.contains(&lib.get_export_node_id(&c)));
+
+    let internal_exports: Vec<String> =
+        lib.internal_exports().iter().map(|proc_name| proc_name.to_string()).collect();
+    assert_eq!(internal_exports, ["lib1::foo::b", "lib1::foo::d"]);
+
+    Ok(())
+}
+
+#[test]
+fn kernel_library_procedure_ids() {
+    let kernel = r#"
+        export.foo
+            push.1 add
+        end
+
+        export.bar
+            push.2 mul
+        end
+    "#;
+    let kernel_lib = Assembler::new(TestContext::new().source_manager())
+        .assemble_kernel(kernel)
+        .unwrap();
+
+    let proc_ids = kernel_lib.kernel_procedure_ids();
+    assert_eq!(proc_ids.len(), 2);
+    for (proc_id, proc_hash) in proc_ids.iter().zip(kernel_lib.kernel().proc_hashes()) {
+        assert!(kernel_lib.mast_forest().is_procedure_root(*proc_id));
+        assert_eq!(kernel_lib.mast_forest()[*proc_id].digest(), *proc_hash);
+    }
+}
+
+#[test]
+fn library_rename_namespace() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let foo = parse_module!(&context, "old::foo", "export.foo1 push.1 push.2 add end");
+    let bar = parse_module!(&context, "old::math::bar", "export.bar1 push.3 mul end");
+    let mut lib = Assembler::new(context.source_manager()).assemble_library([foo, bar])?;
+    let digest = *lib.digest();
+    let export_digests: Vec<_> = lib
+        .exports()
+        .map(|proc_name| lib.mast_forest()[lib.get_export_node_id(proc_name)].digest())
+        .collect();
+
+    let old_ns = LibraryNamespace::new("old").unwrap();
+    lib.rename_namespace(&old_ns, LibraryNamespace::new("new").unwrap()).unwrap();
+
+    let exports: Vec<String> = lib.exports().map(|proc_name| proc_name.to_string()).collect();
+    assert_eq!(exports, ["new::foo::foo1", "new::math::bar::bar1"]);
+    assert_eq!(lib.digest(), &digest);
+    let renamed_export_digests: Vec<_> = lib
+        .exports()
+        .map(|proc_name| lib.mast_forest()[lib.get_export_node_id(proc_name)].digest())
+        .collect();
+    assert_eq!(renamed_export_digests, export_digests);
+
+    // reserved namespaces cannot be used for user libraries
+    let new_ns = LibraryNamespace::new("new").unwrap();
+    let err = lib.rename_namespace(&new_ns, LibraryNamespace::Kernel).unwrap_err();
+    assert!(
+        matches!(err, LibraryError::Namespace(LibraryNamespaceError::Reserved(_))),
+        "{err}"
+    );
+    assert_eq!(lib.exports().next().unwrap().module.namespace(), &new_ns);
+
+    Ok(())
+}
+
+#[test]
+fn library_rename_namespace_collision() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let old_foo = parse_module!(&context, "old::foo", "export.foo1 push.1 push.2 add end");
+    let new_foo = parse_module!(&context, "new::foo", "export.foo1 push.3 mul end");
+    let mut lib = Assembler::new(context.source_manager()).assemble_library([old_foo, new_foo])?;
+    let exports: Vec<String> = lib.exports().map(|proc_name| proc_name.to_string()).collect();
+
+    let old_ns = LibraryNamespace::new("old").unwrap();
+    let err = lib
+        .rename_namespace(&old_ns, LibraryNamespace::new("new").unwrap())
+        .unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            LibraryError::DuplicateExport { procedure_path }
+                if procedure_path.to_string() == "new::foo::foo1"
+        ),
+        "{err}"
+    );
+
+    // the library is left unchanged
+    let exports_after: Vec<String> = lib.exports().map(|proc_name| proc_name.to_string()).collect();
+    assert_eq!(exports_after, exports);
+    assert_eq!(exports_after, ["new::foo::foo1", "old::foo::foo1"]);
+
+    // renaming a namespace to itself is a no-op
+    lib.rename_namespace(&old_ns, old_ns.clone()).unwrap();
+    assert_eq!(lib.exports().count(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn library_statistics() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let foo = parse_module!(&context, "lib1::foo", "export.foo1 push.1 push.2 add end");
+    let lib1 = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    let bar = r#"
+        use.lib1::foo
+
+        export.bar1
+            exec.foo::foo1
+            push.3 mul
+        end
+
+        export.bar2
+            push.4
+        end
+    "#;
+    let bar = parse_module!(&context, "lib2::bar", bar);
+    let baz = parse_module!(&context, "lib2::baz", "export.baz1 push.5 push.6 mul end");
+    let modules = [bar, baz];
+
+    let lib2 = Assembler::new(context.source_manager())
+        .with_library(&lib1)?
+        .assemble_library(modules.iter().cloned())?;
+    let stats = lib2.statistics();
+    assert_eq!(stats.num_modules, 2);
+    assert_eq!(stats.num_exports, 3);
+    // bar1 is a JOIN of the external foo1 node and a basic block; bar2 and baz1 are basic blocks
+    assert_eq!(stats.num_nodes, 5);
+    assert_eq!(stats.num_external_dependencies, 1);
+    assert!(!stats.has_debug_info);
+
+    let lib2 = Assembler::new(context.source_manager())
+        .with_debug_mode(true)
+        .with_library(&lib1)?
+        .assemble_library(modules.iter().cloned())?;
+    assert!(lib2.statistics().has_debug_info);
+
+    Ok(())
+}
+
 #[test]
 fn library_serialization() -> Result<(), Report> {
     let context = TestContext::new();
@@ -208,6 +405,52 @@ fn library_serialization() -> Result<(), Report> {
     Ok(())
 }
 
+#[test]
+fn library_deserialization_rejects_cyclic_forest() {
+    let mut mast_forest = MastForest::new();
+    let block = mast_forest.add_block(vec![Operation::Add], None).unwrap();
+    let loop_id = mast_forest.add_loop(block).unwrap();
+    mast_forest.make_root(loop_id);
+    // make the loop its own body
+    mast_forest[loop_id] = MastNode::Loop(LoopNode::new(loop_id, &mast_forest).unwrap());
+
+    let foo = QualifiedProcedureName::from_str("test::foo::foo").unwrap();
+    let library = Library::new(mast_forest.into(), [(foo, loop_id)].into()).unwrap();
+
+    let err = Library::read_from_bytes(&library.to_bytes()).unwrap_err();
+    assert_eq!(
+        err,
+        DeserializationError::InvalidValue(format!(
+            "malformed library MAST forest: {}",
+            MastForestError::NodeCycle(loop_id)
+        ))
+    );
+}
+
+#[test]
+fn library_deserialization_rejects_non_root_exports() {
+    let mut mast_forest = MastForest::new();
+    let block = mast_forest.add_block(vec![Operation::Add], None).unwrap();
+    let loop_id = mast_forest.add_loop(block).unwrap();
+    mast_forest.make_root(loop_id);
+
+    let foo = QualifiedProcedureName::new(
+        LibraryPath::from(LibraryNamespace::Kernel),
+        ProcedureName::new("foo").unwrap(),
+    );
+    let mut library = Library::new(mast_forest.into(), [(foo.clone(), loop_id)].into()).unwrap();
+    // point the export at the body of the loop, which is not a procedure root
+    library.exports.insert(foo.clone(), block);
+    let bytes = library.to_bytes();
+
+    let expected_err = DeserializationError::InvalidValue(format!(
+        "malformed library exports: {}",
+        LibraryError::NoProcedureRootForExport { procedure_path: foo }
+    ));
+    assert_eq!(Library::read_from_bytes(&bytes).unwrap_err(), expected_err);
+    assert_eq!(KernelLibrary::read_from_bytes(&bytes).unwrap_err(), expected_err);
+}
+
 #[test]
 fn get_module_by_path() -> Result<(), Report> {
     let context = TestContext::new();
@@ -233,3 +476,179 @@ fn get_module_by_path() -> Result<(), Report> {
 
     Ok(())
 }
+
+#[test]
+fn into_module_map() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo_source = r#"
+        export.foo1
+            add
+        end
+
+        export.foo2
+            mul
+        end
+    "#;
+    let bar_source = r#"
+        export.bar
+            sub
+        end
+    "#;
+    let foo = parse_module!(&context, "test::foo", foo_source);
+    let bar = parse_module!(&context, "test::bar", bar_source);
+
+    let library = Assembler::new(context.source_manager()).assemble_library([foo, bar])?;
+
+    // the keys of the module map are the distinct module paths of the exports
+    let export_modules: BTreeSet<_> =
+        library.exports().map(|export| export.module.clone()).collect();
+    let module_map = library.into_module_map();
+    assert_eq!(export_modules, module_map.keys().cloned().collect());
+    assert_eq!(2, module_map.len());
+
+    for (path, module_info) in module_map.iter() {
+        assert_eq!(path, module_info.path());
+    }
+    assert_eq!(2, module_map[&LibraryPath::new("test::foo").unwrap()].procedures().count());
+    assert_eq!(1, module_map[&LibraryPath::new("test::bar").unwrap()].procedures().count());
+
+    Ok(())
+}
+
+#[test]
+fn library_diff() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo_source = r#"
+        export.foo1
+            add
+        end
+
+        export.foo2
+            mul
+        end
+    "#;
+    let bar_source = r#"
+        export.bar
+            sub
+        end
+    "#;
+    let foo = parse_module!(&context, "test::foo", foo_source);
+    let bar = parse_module!(&context, "test::bar", bar_source);
+    let old_library = Assembler::new(context.source_manager()).assemble_library([foo, bar])?;
+
+    // change foo2, remove bar, and add baz
+    let foo_source = r#"
+        export.foo1
+            add
+        end
+
+        export.foo2
+            mul mul
+        end
+    "#;
+    let baz_source = r#"
+        export.baz
+            sub
+        end
+    "#;
+    let foo = parse_module!(&context, "test::foo", foo_source);
+    let baz = parse_module!(&context, "test::baz", baz_source);
+    let new_library = Assembler::new(context.source_manager()).assemble_library([foo, baz])?;
+
+    let bar = QualifiedProcedureName::from_str("test::bar::bar").unwrap();
+    let baz = QualifiedProcedureName::from_str("test::baz::baz").unwrap();
+    let foo2 = QualifiedProcedureName::from_str("test::foo::foo2").unwrap();
+
+    let diff = old_library.diff(&new_library);
+    assert_eq!(diff.added, vec![baz.clone()]);
+    assert_eq!(diff.removed, vec![bar.clone()]);
+    let old_foo2_digest = old_library.mast_forest()[old_library.get_export_node_id(&foo2)].digest();
+    let new_foo2_digest = new_library.mast_forest()[new_library.get_export_node_id(&foo2)].digest();
+    assert_eq!(diff.changed, vec![(foo2.clone(), old_foo2_digest, new_foo2_digest)]);
+
+    // diffing in the other direction swaps additions and removals
+    let reverse_diff = new_library.diff(&old_library);
+    assert_eq!(reverse_diff.added, vec![bar]);
+    assert_eq!(reverse_diff.removed, vec![baz]);
+    assert_eq!(reverse_diff.changed, vec![(foo2, new_foo2_digest, old_foo2_digest)]);
+
+    assert!(old_library.diff(&old_library).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn version_compatibility() {
+    let version = |s: &str| Version::from_str(s).unwrap();
+
+    // newer minor and patch versions are compatible within the same major version
+    assert!(version("1.2.3").is_compatible_with(&version("1.1.0")));
+    assert!(version("1.2.3").is_compatible_with(&version("1.2.3")));
+    assert!(!version("1.2.3").is_compatible_with(&version("1.2.4")));
+    assert!(!version("1.2.3").is_compatible_with(&version("1.3.0")));
+
+    // different major versions are not compatible
+    assert!(!version("1.2.3").is_compatible_with(&version("2.0.0")));
+    assert!(!version("2.0.0").is_compatible_with(&version("1.2.3")));
+
+    // for 0.x versions, the minor versions must match
+    assert!(version("0.3.1").is_compatible_with(&version("0.3.0")));
+    assert!(!version("0.3.0").is_compatible_with(&version("0.3.1")));
+    assert!(!version("0.4.0").is_compatible_with(&version("0.3.0")));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn library_from_dir_rejects_reserved_namespace() {
+    for namespace in [LibraryNamespace::Kernel, LibraryNamespace::Exec, LibraryNamespace::Anon] {
+        let err = Library::from_dir("unused", namespace.clone(), Assembler::default())
+            .expect_err("reserved namespaces should be rejected");
+        assert_eq!(
+            err.downcast_ref::<LibraryNamespaceError>(),
+            Some(&LibraryNamespaceError::Reserved(namespace.as_refcounted_str()))
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn library_write_to_file_without_overwrite() -> Result<(), Report> {
+    let context = TestContext::new();
+    let foo = parse_module!(&context, "test::foo", "export.foo add end");
+    let library = Assembler::new(context.source_manager()).assemble_library([foo])?;
+
+    let dir = std::env::temp_dir().join(format!("miden-library-write-{}", std::process::id()));
+    let path = dir.join("nested").join("test.masl");
+    let no_overwrite = WriteOptions { overwrite: false, create_dirs: true };
+
+    library.write_to_file_with_options(&path, no_overwrite).unwrap();
+    let err = library.write_to_file_with_options(&path, no_overwrite).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+
+    // overwriting is still possible when explicitly allowed
+    library.write_to_file(&path).unwrap();
+    assert_eq!(Library::deserialize_from_file(&path).unwrap(), library);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn library_from_dir_maps_nested_modules() -> Result<(), Report> {
+    let dir = std::env::temp_dir().join(format!("miden-library-from-dir-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("math")).unwrap();
+    std::fs::write(dir.join("math").join("u64.masm"), "export.add add end").unwrap();
+    std::fs::write(dir.join("math").join("README.md"), "# not a module").unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a module").unwrap();
+
+    let namespace = LibraryNamespace::new("ns").unwrap();
+    let library = Library::from_dir(&dir, namespace, Assembler::default());
+    std::fs::remove_dir_all(&dir).unwrap();
+    let library = library?;
+
+    let module_paths: Vec<_> = library.module_infos().map(|info| info.path().to_string()).collect();
+    assert_eq!(module_paths, ["ns::math::u64"]);
+
+    Ok(())
+}
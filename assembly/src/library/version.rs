@@ -64,6 +64,28 @@ impl Version {
     }
 }
 
+/// Compatibility
+impl Version {
+    /// Returns true if this version can be used where the `required` version is expected.
+    ///
+    /// This follows caret-style (`^`) semantic versioning rules: this version must be greater than
+    /// or equal to the `required` version, and must have the same major version. For versions
+    /// before `1.0.0`, where minor version changes are considered breaking, the minor versions must
+    /// match as well.
+    pub const fn is_compatible_with(&self, required: &Version) -> bool {
+        let same_major = self.major == required.major;
+        let same_minor = self.minor == required.minor;
+        let at_least_required =
+            self.minor > required.minor || (same_minor && self.patch >= required.patch);
+
+        if required.major == 0 {
+            same_major && same_minor && self.patch >= required.patch
+        } else {
+            same_major && at_least_required
+        }
+    }
+}
+
 impl Default for Version {
     fn default() -> Self {
         Self::min()
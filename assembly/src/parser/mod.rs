@@ -185,6 +185,9 @@ pub fn read_modules_from_dir(
 
     use crate::diagnostics::{IntoDiagnostic, WrapErr};
 
+    // modules loaded from a directory always belong to a user library
+    namespace.validate_user_namespace()?;
+
     if !dir.is_dir() {
         return Err(miette!("the provided path '{}' is not a valid directory", dir.display()));
     }
@@ -207,7 +210,13 @@ pub fn read_modules_from_dir(
 
         // Parse module at the given path
         let mut parser = ModuleParser::new(ast::ModuleKind::Library);
-        let ast = parser.parse_file(name.clone(), &source_path, source_manager)?;
+        let ast =
+            parser
+                .parse_file(name.clone(), &source_path, source_manager)
+                .wrap_err_with(|| {
+                    let relative_path = source_path.strip_prefix(dir).unwrap_or(&source_path);
+                    format!("error in {}", relative_path.display())
+                })?;
         match modules.entry(name) {
             Entry::Occupied(ref entry) => {
                 return Err(miette!("duplicate module '{0}'", entry.key().clone()));
@@ -435,6 +435,69 @@ fn procref_call() -> TestResult {
     Ok(())
 }
 
+/// Procedures which are only known by their MAST root (i.e., procedures from a library dependency,
+/// or procedures referenced directly by their MAST root) are represented by external nodes, so
+/// `procref` must push the digest of the external node.
+#[test]
+fn procref_of_external_procedure() -> TestResult {
+    let mut context = TestContext::default();
+    let module = context.parse_module_with_path(
+        "lib::foo".parse().unwrap(),
+        source_file!(
+            &context,
+            "
+        export.bar
+            push.1.2 add
+        end"
+        ),
+    )?;
+    let library = context.assemble_library(core::iter::once(module))?;
+    let bar_root = library
+        .mast_forest()
+        .get_node_by_id(library.get_export_node_id(&"lib::foo::bar".parse().unwrap()))
+        .unwrap()
+        .digest();
+    context.add_library(&library)?;
+
+    let program = context.assemble(source_file!(
+        &context,
+        format!(
+            "
+        use.lib::foo
+
+        begin
+            procref.foo::bar
+            procref.{bar_root}
+        end"
+        )
+    ))?;
+
+    // the procedure body is not part of the program, only an external reference to it
+    let external_node = program
+        .mast_forest()
+        .nodes()
+        .iter()
+        .find(|node| node.is_external())
+        .expect("expected an external node for the referenced procedure");
+    assert_eq!(external_node.digest(), bar_root);
+
+    // both references push the MAST root of the procedure
+    let entrypoint = program.get_node_by_id(program.entrypoint()).unwrap();
+    let pushed_values: Vec<_> = entrypoint
+        .get_basic_block()
+        .expect("expected the entrypoint to be a basic block")
+        .operations()
+        .filter_map(|op| match op {
+            vm_core::Operation::Push(value) => Some(*value),
+            _ => None,
+        })
+        .collect();
+    let expected_values: Vec<_> = bar_root.iter().chain(bar_root.iter()).copied().collect();
+    assert_eq!(pushed_values, expected_values);
+
+    Ok(())
+}
+
 #[test]
 fn get_proc_name_of_unknown_module() -> TestResult {
     let context = TestContext::default();
@@ -3028,3 +3091,65 @@ fn test_program_serde_with_decorators() {
 
     assert_eq!(original_program, deserialized_program);
 }
+
+#[test]
+fn asm_op_ranges_tile_basic_block() {
+    let source = "
+    begin
+        push.1 push.2 add
+        mul.3 u32split
+        dup.5 swap.2 drop
+    end";
+
+    let assembler = Assembler::default().with_debug_mode(true);
+    let program = assembler.assemble_program(source).unwrap();
+    let mast_forest = program.mast_forest();
+    let MastNode::Block(basic_block) = &mast_forest[program.entrypoint()] else {
+        panic!("expected a basic block");
+    };
+
+    let ranges: Vec<_> = basic_block
+        .asm_op_ranges(mast_forest)
+        .map(|(range, assembly_op)| (range, assembly_op.op().to_string()))
+        .collect();
+    let ops: Vec<_> = ranges.iter().map(|(_, op)| op.as_str()).collect();
+    assert_eq!(ops, ["push.1", "push.2", "add", "mul.3", "u32split", "dup.5", "swap.2", "drop"]);
+
+    // the ranges cover all operations of the block without gaps or overlaps
+    let mut next_op_idx = 0;
+    for (range, op) in ranges.iter() {
+        assert_eq!(range.start, next_op_idx, "{op}");
+        assert!(!range.is_empty(), "{op}");
+        next_op_idx = range.end;
+    }
+    assert_eq!(next_op_idx, basic_block.num_operations() as usize);
+}
+
+#[test]
+fn mast_forest_content_hash_differs_between_debug_and_release() {
+    let source = "
+    proc.foo
+        push.1 add
+    end
+
+    proc.bar
+        push.1 add
+    end
+
+    begin
+        if.true
+            exec.foo
+        else
+            exec.bar
+        end
+    end";
+
+    let release = Assembler::default().assemble_program(source).unwrap();
+    let debug = Assembler::default().with_debug_mode(true).assemble_program(source).unwrap();
+    assert_eq!(release.hash(), debug.hash());
+
+    // in debug mode, the bodies of `foo` and `bar` carry different decorators and are not
+    // deduplicated, so the forests have different nodes and hence different content hashes
+    assert!(release.mast_forest().num_nodes() < debug.mast_forest().num_nodes());
+    assert_ne!(release.mast_forest().content_hash(), debug.mast_forest().content_hash());
+}
@@ -0,0 +1,262 @@
+use alloc::{
+    collections::{btree_map::IntoIter, BTreeMap},
+    format,
+    vec::Vec,
+};
+
+use miden_crypto::{hash::rpo::RpoDigest, Felt};
+
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    FieldElement,
+};
+
+// ADVICE MAP
+// ================================================================================================
+
+/// Defines a set of non-deterministic (advice) inputs which the VM can access by their keys.
+///
+/// Each key maps to one or more field element. To access the elements, the VM can move the values
+/// associated with a given key onto the advice stack using `adv.push_mapval` instruction. The VM
+/// can also insert new values into the advice map during execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdviceMap(BTreeMap<RpoDigest, Vec<Felt>>);
+
+impl AdviceMap {
+    /// Creates a new advice map.
+    ///
+    /// There is no `with_capacity()` counterpart: the advice map is backed by a [BTreeMap], which
+    /// allocates its nodes as entries are inserted rather than reserving space upfront.
+    pub fn new() -> Self {
+        Self(BTreeMap::<RpoDigest, Vec<Felt>>::new())
+    }
+
+    /// Returns the values associated with given key.
+    pub fn get(&self, key: &RpoDigest) -> Option<&[Felt]> {
+        self.0.get(key).map(|v| v.as_slice())
+    }
+
+    /// Inserts a key value pair in the advice map and returns the inserted value.
+    pub fn insert(&mut self, key: RpoDigest, value: Vec<Felt>) -> Option<Vec<Felt>> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes the value associated with the key and returns the removed element.
+    pub fn remove(&mut self, key: RpoDigest) -> Option<Vec<Felt>> {
+        self.0.remove(&key)
+    }
+
+    /// Returns an iterator over the key-value pairs of this advice map, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&RpoDigest, &Vec<Felt>)> {
+        self.0.iter()
+    }
+
+    /// Returns true if the advice map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<BTreeMap<RpoDigest, Vec<Felt>>> for AdviceMap {
+    fn from(value: BTreeMap<RpoDigest, Vec<Felt>>) -> Self {
+        Self(value)
+    }
+}
+
+impl IntoIterator for AdviceMap {
+    type Item = (RpoDigest, Vec<Felt>);
+    type IntoIter = IntoIter<RpoDigest, Vec<Felt>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Extend<(RpoDigest, Vec<Felt>)> for AdviceMap {
+    fn extend<T: IntoIterator<Item = (RpoDigest, Vec<Felt>)>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+/// The serialized form of an advice map is canonical: entries are written in ascending key order,
+/// so two maps holding the same entries serialize to identical bytes regardless of the order in
+/// which the entries were inserted.
+impl Serializable for AdviceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        for (key, values) in self.0.iter() {
+            target.write((key, values));
+        }
+    }
+}
+
+impl Deserializable for AdviceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let count = source.read_usize()?;
+        check_remaining_len(source, count, MIN_ENTRY_SIZE, "advice map entries")?;
+
+        // the checks above only bound the lengths for readers which know how many bytes remain
+        // (e.g., `ReadAdapter` optimistically accepts any length before reaching the end of its
+        // input), so no space is reserved upfront based on lengths read from the source
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let key: RpoDigest = source.read()?;
+            let num_values = source.read_usize()?;
+            check_remaining_len(source, num_values, Felt::ELEMENT_BYTES, "advice map values")?;
+            let mut values = Vec::new();
+            for _ in 0..num_values {
+                values.push(source.read()?);
+            }
+            entries.push((key, values));
+        }
+
+        // entries are serialized in key order, and building a BTreeMap from sorted entries is more
+        // efficient than inserting them one by one
+        Ok(Self(BTreeMap::from_iter(entries)))
+    }
+}
+
+/// The minimum number of bytes a serialized advice map entry occupies: the key followed by at
+/// least one byte encoding the number of values.
+const MIN_ENTRY_SIZE: usize = RpoDigest::SERIALIZED_SIZE + 1;
+
+/// Makes sure that the reader holds enough bytes for `count` items of at least `item_size` bytes
+/// each, so that a corrupted or malicious length prefix cannot cause unbounded allocations.
+fn check_remaining_len<R: ByteReader>(
+    source: &R,
+    count: usize,
+    item_size: usize,
+    items: &str,
+) -> Result<(), DeserializationError> {
+    count
+        .checked_mul(item_size)
+        .and_then(|num_bytes| source.check_eor(num_bytes).ok())
+        .ok_or_else(|| {
+            DeserializationError::InvalidValue(format!(
+                "number of {items} ({count}) exceeds the number of remaining bytes"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZERO;
+
+    #[test]
+    fn test_advice_map_serialization() {
+        let mut map1 = AdviceMap::new();
+        map1.insert(RpoDigest::default(), vec![Felt::from(1u32), Felt::from(2u32)]);
+
+        let bytes = map1.to_bytes();
+
+        let map2 = AdviceMap::read_from_bytes(&bytes).unwrap();
+
+        assert_eq!(map1, map2);
+    }
+
+    #[test]
+    fn test_large_advice_map_serialization() {
+        let map1: AdviceMap = (0..10_000u64)
+            .map(|i| {
+                let key = RpoDigest::new([
+                    Felt::new(i),
+                    Felt::new(i + 1),
+                    Felt::new(i + 2),
+                    Felt::new(i + 3),
+                ]);
+                let values = (0..i % 8).map(|j| Felt::new(i * j)).collect();
+                (key, values)
+            })
+            .collect::<BTreeMap<_, _>>()
+            .into();
+
+        let bytes = map1.to_bytes();
+        let map2 = AdviceMap::read_from_bytes(&bytes).unwrap();
+
+        assert_eq!(map1.iter().count(), 10_000);
+        assert_eq!(map1, map2);
+    }
+
+    #[test]
+    fn test_advice_map_deserialization_rejects_oversized_lengths() {
+        // a map claiming to contain usize::MAX entries
+        let mut bytes = Vec::new();
+        bytes.write_usize(usize::MAX);
+        bytes.write(RpoDigest::default());
+        let err = AdviceMap::read_from_bytes(&bytes).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)), "{err}");
+
+        // a single entry claiming to contain usize::MAX values
+        let mut bytes = Vec::new();
+        bytes.write_usize(1);
+        bytes.write(RpoDigest::default());
+        bytes.write_usize(usize::MAX);
+        bytes.write(Felt::from(1u32));
+        let err = AdviceMap::read_from_bytes(&bytes).unwrap_err();
+        assert!(matches!(err, DeserializationError::InvalidValue(_)), "{err}");
+
+        // the same lengths read through an adapter which cannot tell how many bytes remain
+        #[cfg(feature = "std")]
+        for num_entries in [usize::MAX, usize::MAX / MIN_ENTRY_SIZE] {
+            use crate::utils::ReadAdapter;
+
+            let mut bytes = Vec::new();
+            bytes.write_usize(num_entries);
+            bytes.write(RpoDigest::default());
+            let mut source = bytes.as_slice();
+            let err = AdviceMap::read_from(&mut ReadAdapter::new(&mut source)).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    DeserializationError::InvalidValue(_) | DeserializationError::UnexpectedEOF
+                ),
+                "{err}"
+            );
+
+            let mut bytes = Vec::new();
+            bytes.write_usize(1);
+            bytes.write(RpoDigest::default());
+            bytes.write_usize(num_entries);
+            bytes.write(Felt::from(1u32));
+            let mut source = bytes.as_slice();
+            let err = AdviceMap::read_from(&mut ReadAdapter::new(&mut source)).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    DeserializationError::InvalidValue(_) | DeserializationError::UnexpectedEOF
+                ),
+                "{err}"
+            );
+        }
+
+        // a truncated buffer with a plausible number of entries
+        let mut map = AdviceMap::new();
+        map.insert(RpoDigest::default(), vec![Felt::from(1u32), Felt::from(2u32)]);
+        let bytes = map.to_bytes();
+        assert!(AdviceMap::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_advice_map_serialization_is_canonical() {
+        let entries: Vec<(RpoDigest, Vec<Felt>)> = (0..16u64)
+            .map(|i| {
+                // spread the keys so that insertion order differs from key order
+                let key = RpoDigest::new([Felt::new(i * 7 % 16), ZERO, ZERO, Felt::new(i)]);
+                (key, vec![Felt::new(i); (i % 3) as usize + 1])
+            })
+            .collect();
+
+        let mut map1 = AdviceMap::new();
+        for (key, values) in entries.iter().cloned() {
+            map1.insert(key, values);
+        }
+
+        let mut map2 = AdviceMap::new();
+        for (key, values) in entries.into_iter().rev() {
+            map2.insert(key, values);
+        }
+
+        assert_eq!(map1.to_bytes(), map2.to_bytes());
+    }
+}
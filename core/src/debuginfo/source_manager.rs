@@ -248,6 +248,7 @@ impl DefaultSourceManagerImpl {
             .expect("system limit: source manager has exhausted its supply of source ids");
         let file = Arc::new(SourceFile::from_raw_parts(id, content));
         self.files.push(Arc::clone(&file));
+        self.names.insert(name, id);
         file
     }
 
@@ -367,3 +368,34 @@ impl SourceManager for DefaultSourceManager {
             .ok_or(SourceManagerError::InvalidBounds)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_source_manager_reload_with_new_content() {
+        let source_manager = DefaultSourceManager::default();
+        let first = source_manager.load("foo.masm", "begin push.1 end".to_string());
+        let second = source_manager.load("foo.masm", "begin push.1 push.2 add end".to_string());
+        assert_ne!(first.id(), second.id());
+
+        // lookups by name resolve to the most recently loaded content
+        let file = source_manager.get_by_path("foo.masm").unwrap();
+        assert_eq!(file.id(), second.id());
+        assert_eq!(file.as_str(), "begin push.1 push.2 add end");
+
+        // the location is out of bounds for the first content, but not for the second one
+        let location = Location::new("foo.masm".into(), 20.into(), 23.into());
+        let span = source_manager.location_to_span(location).unwrap();
+        assert_eq!(span.source_id(), second.id());
+        assert_eq!(source_manager.source_slice(span).unwrap(), "add");
+
+        // loading the same content again returns the existing file
+        let third = source_manager.load("foo.masm", "begin push.1 push.2 add end".to_string());
+        assert_eq!(third.id(), second.id());
+    }
+}
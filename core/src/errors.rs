@@ -2,6 +2,8 @@ use alloc::string::String;
 
 use miden_formatting::hex::DisplayHex;
 
+use crate::mast::MastNodeId;
+
 // INPUT ERROR
 // ================================================================================================
 
@@ -40,3 +42,14 @@ pub enum KernelError {
     #[error("kernel can have at most {0} procedures, received {1}")]
     TooManyProcedures(usize, usize),
 }
+
+// PROGRAM ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProgramError {
+    #[error("entrypoint {0} is not a node of the MAST forest, which contains {1} nodes")]
+    EntrypointNotInForest(MastNodeId, usize),
+    #[error("entrypoint {0} is not a procedure root of the MAST forest")]
+    EntrypointNotProcedureRoot(MastNodeId),
+}
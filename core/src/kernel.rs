@@ -53,6 +53,16 @@ impl Kernel {
     pub fn proc_hashes(&self) -> &[RpoDigest] {
         &self.0
     }
+
+    /// Returns an iterator over the procedures of this kernel, yielding the index of each
+    /// procedure together with its hash.
+    ///
+    /// The indices are contiguous starting from 0, and follow the consistent order in which the
+    /// procedures are stored in this kernel (see [Kernel::proc_hashes]). This is the same order
+    /// in which the procedures are laid out in the kernel ROM chiplet.
+    pub fn procedures(&self) -> impl Iterator<Item = (usize, RpoDigest)> + '_ {
+        self.0.iter().copied().enumerate()
+    }
 }
 
 // this is required by AIR as public inputs will be serialized with the proof
@@ -71,3 +81,30 @@ impl Deserializable for Kernel {
         Ok(Self(kernel))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Felt;
+
+    #[test]
+    fn kernel_procedures() {
+        let proc_hashes: Vec<RpoDigest> = (0..5_u64)
+            .map(|i| RpoDigest::from([Felt::new(i), Felt::new(i + 1), Felt::new(0), Felt::new(0)]))
+            .collect();
+        let kernel = Kernel::new(&proc_hashes).unwrap();
+
+        // indices are contiguous from 0 and follow the order of the stored procedure hashes
+        let procedures: Vec<_> = kernel.procedures().collect();
+        assert_eq!(procedures.len(), proc_hashes.len());
+        for (expected_idx, (idx, digest)) in procedures.iter().enumerate() {
+            assert_eq!(*idx, expected_idx);
+            assert_eq!(*digest, kernel.proc_hashes()[expected_idx]);
+        }
+
+        // the order of the procedures does not depend on the order they were provided in
+        let reversed: Vec<_> = proc_hashes.iter().rev().copied().collect();
+        let kernel_reversed = Kernel::new(&reversed).unwrap();
+        assert_eq!(kernel_reversed.procedures().collect::<Vec<_>>(), procedures);
+    }
+}
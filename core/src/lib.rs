@@ -49,6 +49,9 @@ assertion failed: `(left matches right)`
     }
 }
 
+mod advice;
+pub use advice::AdviceMap;
+
 pub mod chiplets;
 pub mod debuginfo;
 pub mod errors;
@@ -117,7 +120,7 @@ pub mod prettier {
 mod operations;
 pub use operations::{
     opcode_constants::*, AdviceInjector, AssemblyOp, DebugOptions, Decorator, DecoratorIterator,
-    DecoratorList, Operation, SignatureKind,
+    DecoratorList, OpCodeInfo, Operation, SignatureKind,
 };
 
 pub mod stack;
@@ -31,6 +31,9 @@ pub(crate) struct MastForestMerger {
     ///
     /// Any `MastNodeId` in `mast_forest` is present as the target of some mapping in this map.
     node_id_mappings: Vec<MastForestNodeIdMap>,
+    /// The index of the forest from which each node in `mast_forest` was taken, indexed by the
+    /// node's id in `mast_forest`.
+    node_provenance: Vec<usize>,
 }
 
 impl MastForestMerger {
@@ -38,7 +41,7 @@ impl MastForestMerger {
     /// into it.
     pub(crate) fn merge<'forest>(
         forests: impl IntoIterator<Item = &'forest MastForest>,
-    ) -> Result<(MastForest, MastForestRootMap), MastForestError> {
+    ) -> Result<(MastForest, MastForestRootMap, MastForestProvenance), MastForestError> {
         let forests = forests.into_iter().collect::<Vec<_>>();
         let decorator_id_mappings = Vec::with_capacity(forests.len());
         let node_id_mappings = vec![MastForestNodeIdMap::new(); forests.len()];
@@ -50,24 +53,31 @@ impl MastForestMerger {
             mast_forest: MastForest::new(),
             decorator_id_mappings,
             node_id_mappings,
+            node_provenance: Vec::new(),
         };
 
         merger.merge_inner(forests.clone())?;
 
-        let Self { mast_forest, node_id_mappings, .. } = merger;
+        let Self {
+            mast_forest,
+            node_id_mappings,
+            node_provenance,
+            ..
+        } = merger;
 
         let root_maps = MastForestRootMap::from_node_id_map(node_id_mappings, forests);
 
-        Ok((mast_forest, root_maps))
+        Ok((mast_forest, root_maps, MastForestProvenance { node_provenance }))
     }
 
     /// Merges all `forests` into self.
     ///
     /// It does this in three steps:
     ///
-    /// 1. Merge all decorators, which is a case of deduplication and creating a decorator id
-    ///    mapping which contains how existing [`DecoratorId`]s map to [`DecoratorId`]s in the
-    ///    merged forest.
+    /// 1. Merge all advice maps and decorators. Advice map entries are deduplicated, and an error
+    ///    is returned if the same key maps to different values in two forests. Merging decorators
+    ///    is a case of deduplication and creating a decorator id mapping which contains how
+    ///    existing [`DecoratorId`]s map to [`DecoratorId`]s in the merged forest.
     /// 2. Merge all nodes of forests.
     ///    - Similar to decorators, node indices might move during merging, so the merger keeps a
     ///      node id mapping as it merges nodes.
@@ -95,6 +105,7 @@ impl MastForestMerger {
     ///    deduplicating in the process, too.
     fn merge_inner(&mut self, forests: Vec<&MastForest>) -> Result<(), MastForestError> {
         for other_forest in forests.iter() {
+            self.merge_advice_map(other_forest)?;
             self.merge_decorators(other_forest)?;
         }
 
@@ -139,6 +150,26 @@ impl MastForestMerger {
         Ok(())
     }
 
+    /// Merges the advice map of `other_forest` into the advice map of the merged forest.
+    ///
+    /// Entries which are present in both maps with the same value are deduplicated, while a key
+    /// which maps to different values results in an error.
+    fn merge_advice_map(&mut self, other_forest: &MastForest) -> Result<(), MastForestError> {
+        for (key, values) in other_forest.advice_map.iter() {
+            match self.mast_forest.advice_map.get(key) {
+                Some(existing_values) if existing_values != values.as_slice() => {
+                    return Err(MastForestError::AdviceMapKeyCollisionOnMerge(*key));
+                },
+                Some(_) => (),
+                None => {
+                    self.mast_forest.advice_map.insert(*key, values.clone());
+                },
+            }
+        }
+
+        Ok(())
+    }
+
     fn merge_decorators(&mut self, other_forest: &MastForest) -> Result<(), MastForestError> {
         let mut decorator_id_remapping = DecoratorIdMap::new(other_forest.decorators.len());
 
@@ -202,6 +233,7 @@ impl MastForestMerger {
                 // unique and we can add it to the merged forest.
                 let new_node_id = self.mast_forest.add_node(remapped_node)?;
                 self.node_id_mappings[forest_idx].insert(merging_id, new_node_id);
+                self.node_provenance.push(forest_idx);
 
                 // We need to update the indices with the newly inserted nodes
                 // since the MastNodeFingerprint computation requires all descendants of a node
@@ -363,6 +395,27 @@ impl MastForestRootMap {
     }
 }
 
+// MAST FOREST PROVENANCE
+// ================================================================================================
+
+/// Records which of the merged forests each node of a merged [`MastForest`] was taken from.
+///
+/// A node which is present in several of the merged forests is deduplicated, and is attributed to
+/// the first forest (in the order the forests were passed to the merge) which contains it. See
+/// [`MastForest::merge_tracked`] for more details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MastForestProvenance {
+    node_provenance: Vec<usize>,
+}
+
+impl MastForestProvenance {
+    /// Returns the index of the forest from which the given node of the merged forest was taken,
+    /// or `None` if the node does not exist in the merged forest.
+    pub fn node_provenance(&self, node_id: MastNodeId) -> Option<usize> {
+        self.node_provenance.get(node_id.as_usize()).copied()
+    }
+}
+
 // DECORATOR ID MAP
 // ================================================================================================
 
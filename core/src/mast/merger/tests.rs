@@ -1,4 +1,4 @@
-use miden_crypto::{hash::rpo::RpoDigest, ONE};
+use miden_crypto::{hash::rpo::RpoDigest, Felt, ONE};
 
 use super::*;
 use crate::{Decorator, Operation};
@@ -126,6 +126,50 @@ fn mast_forest_merge_remap() {
     assert_child_id_lt_parent_id(&merged).unwrap();
 }
 
+/// Tests that each node of the merged forest is attributed to the first forest containing it.
+///
+/// [Block(foo), Call(foo)]
+/// +
+/// [Block(foo), Block(bar), Call(bar)]
+/// =
+/// [Block(foo), Call(foo), Block(bar), Call(bar)]
+#[test]
+fn mast_forest_merge_tracked_provenance() {
+    let mut forest_a = MastForest::new();
+    let id_foo_a = forest_a.add_node(block_foo()).unwrap();
+    let id_call_a = forest_a.add_call(id_foo_a).unwrap();
+    forest_a.make_root(id_call_a);
+
+    let mut forest_b = MastForest::new();
+    let id_foo_b = forest_b.add_node(block_foo()).unwrap();
+    let id_bar = forest_b.add_node(block_bar()).unwrap();
+    let id_call_b = forest_b.add_call(id_bar).unwrap();
+    forest_b.make_root(id_foo_b);
+    forest_b.make_root(id_call_b);
+
+    let (merged, root_maps, provenance) =
+        MastForest::merge_tracked([&forest_a, &forest_b]).unwrap();
+    assert_eq!(merged.nodes().len(), 4);
+
+    // the shared node is attributed to the first forest
+    let merged_foo = root_maps.map_root(1, &id_foo_b).unwrap();
+    assert_eq!(merged_foo, id_foo_a);
+    assert_eq!(provenance.node_provenance(merged_foo), Some(0));
+
+    // the nodes unique to a forest are attributed to that forest
+    let merged_call_a = root_maps.map_root(0, &id_call_a).unwrap();
+    let merged_call_b = root_maps.map_root(1, &id_call_b).unwrap();
+    assert_eq!(provenance.node_provenance(merged_call_a), Some(0));
+    assert_eq!(provenance.node_provenance(merged_call_b), Some(1));
+    assert_eq!(provenance.node_provenance(MastNodeId::new_unchecked(2)), Some(1));
+
+    // nodes outside of the merged forest have no provenance
+    assert_eq!(provenance.node_provenance(MastNodeId::new_unchecked(4)), None);
+
+    // merging without tracking produces the same forest
+    assert_eq!(MastForest::merge([&forest_a, &forest_b]).unwrap(), (merged, root_maps));
+}
+
 /// Tests that Forest_A + Forest_A = Forest_A (i.e. duplicates are removed).
 #[test]
 fn mast_forest_merge_duplicate() {
@@ -794,3 +838,40 @@ fn mast_forest_merge_invalid_decorator_index() {
     let err = MastForest::merge([&forest_a, &forest_b]).unwrap_err();
     assert_matches!(err, MastForestError::DecoratorIdOverflow(_, _));
 }
+
+/// Tests that the advice maps of the merged forests are combined, and that merging fails if the
+/// same key maps to different values.
+#[test]
+fn mast_forest_merge_advice_maps() {
+    let key_a = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let key_b = RpoDigest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+    let key_shared = RpoDigest::default();
+
+    let mut forest_a = MastForest::new();
+    let id_foo = forest_a.add_node(block_foo()).unwrap();
+    forest_a.make_root(id_foo);
+    forest_a.advice_map_mut().insert(key_a, vec![ONE]);
+    forest_a.advice_map_mut().insert(key_shared, vec![ONE, ONE]);
+
+    let mut forest_b = MastForest::new();
+    let id_bar = forest_b.add_node(block_bar()).unwrap();
+    forest_b.make_root(id_bar);
+    forest_b.advice_map_mut().insert(key_b, vec![Felt::new(2)]);
+    forest_b.advice_map_mut().insert(key_shared, vec![ONE, ONE]);
+
+    let (merged, _root_maps) = MastForest::merge([&forest_a, &forest_b]).unwrap();
+    let merged_entries: Vec<_> = merged.advice_map().iter().collect();
+    assert_eq!(
+        merged_entries,
+        vec![
+            (&key_shared, &vec![ONE, ONE]),
+            (&key_a, &vec![ONE]),
+            (&key_b, &vec![Felt::new(2)])
+        ]
+    );
+
+    // a key mapped to different values in the two forests is a conflict
+    forest_b.advice_map_mut().insert(key_shared, vec![ONE]);
+    let err = MastForest::merge([&forest_a, &forest_b]).unwrap_err();
+    assert_matches!(err, MastForestError::AdviceMapKeyCollisionOnMerge(key) if key == key_shared);
+}
@@ -16,13 +16,13 @@ pub use node::{
 };
 use winter_utils::{ByteWriter, DeserializationError, Serializable};
 
-use crate::{Decorator, DecoratorList, Operation};
+use crate::{chiplets::hasher, AdviceMap, Decorator, DecoratorList, Felt, Operation};
 
 mod serialization;
 
 mod merger;
 pub(crate) use merger::MastForestMerger;
-pub use merger::MastForestRootMap;
+pub use merger::{MastForestProvenance, MastForestRootMap};
 
 mod multi_forest_node_iterator;
 pub(crate) use multi_forest_node_iterator::*;
@@ -50,6 +50,10 @@ pub struct MastForest {
 
     /// All the decorators included in the MAST forest.
     decorators: Vec<Decorator>,
+
+    /// Advice map which is merged into the advice provider of a host when this MAST forest is
+    /// loaded into the host.
+    advice_map: AdviceMap,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -255,9 +259,28 @@ impl MastForest {
     /// which is effectively deduplication. Decorators are ignored when it comes to merging
     /// External nodes. This means that an External node with decorators may be replaced by a node
     /// without decorators or vice versa.
+    ///
+    /// The advice maps of all `forests` are merged into the advice map of the merged forest. An
+    /// error is returned if the same key is mapped to different values in two of the forests.
     pub fn merge<'forest>(
         forests: impl IntoIterator<Item = &'forest MastForest>,
     ) -> Result<(MastForest, MastForestRootMap), MastForestError> {
+        MastForestMerger::merge(forests)
+            .map(|(mast_forest, root_maps, _provenance)| (mast_forest, root_maps))
+    }
+
+    /// Merges all `forests` into a new [`MastForest`] in the same way as [`MastForest::merge`],
+    /// and additionally returns a [`MastForestProvenance`] recording which of the `forests` each
+    /// node of the merged forest was taken from.
+    ///
+    /// The provenance of a node is the index of its source forest in `forests`, the same index
+    /// which is used to look up roots in the returned [`MastForestRootMap`]. Since duplicate nodes
+    /// are removed during merging, a node which is present in several forests is attributed to
+    /// the first of these forests. This can be used, for example, by a debugger to determine
+    /// which library a procedure call resolves into.
+    pub fn merge_tracked<'forest>(
+        forests: impl IntoIterator<Item = &'forest MastForest>,
+    ) -> Result<(MastForest, MastForestRootMap, MastForestProvenance), MastForestError> {
         MastForestMerger::merge(forests)
     }
 
@@ -411,6 +434,14 @@ impl MastForest {
         self.nodes.get(idx)
     }
 
+    /// Returns the first node in this MAST forest with the specified digest, if any.
+    ///
+    /// Note that several nodes may share the same digest if they differ only in their
+    /// decorators.
+    pub fn get_node_by_digest(&self, digest: RpoDigest) -> Option<&MastNode> {
+        self.nodes.iter().find(|node| node.digest() == digest)
+    }
+
     /// Returns the [`MastNodeId`] of the procedure associated with a given digest, if any.
     #[inline(always)]
     pub fn find_procedure_root(&self, digest: RpoDigest) -> Option<MastNodeId> {
@@ -422,6 +453,20 @@ impl MastForest {
         self.roots.contains(&node_id)
     }
 
+    /// Returns a reference to the advice map of this MAST forest.
+    ///
+    /// The advice map contains the data which is expected to be available in the advice map of
+    /// the VM when procedures from this MAST forest are executed; it is merged into the advice
+    /// provider of a host when this MAST forest is loaded into the host.
+    pub fn advice_map(&self) -> &AdviceMap {
+        &self.advice_map
+    }
+
+    /// Returns a mutable reference to the advice map of this MAST forest.
+    pub fn advice_map_mut(&mut self) -> &mut AdviceMap {
+        &mut self.advice_map
+    }
+
     /// Returns an iterator over the digests of all procedures in this MAST forest.
     pub fn procedure_digests(&self) -> impl Iterator<Item = RpoDigest> + '_ {
         self.roots.iter().map(|&root_id| self[root_id].digest())
@@ -463,6 +508,154 @@ impl MastForest {
     pub fn nodes(&self) -> &[MastNode] {
         &self.nodes
     }
+
+    /// Returns the decorators included in this MAST forest.
+    pub fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
+    /// Returns an iterator over all call edges in this MAST forest.
+    ///
+    /// Each edge is a `(call_node_id, callee_id, is_syscall)` tuple, where `call_node_id` is the ID
+    /// of a CALL or SYSCALL node, `callee_id` is the ID of the node it invokes, and `is_syscall` is
+    /// true if the node is a SYSCALL node.
+    pub fn call_edges(&self) -> impl Iterator<Item = (MastNodeId, MastNodeId, bool)> + '_ {
+        self.nodes.iter().enumerate().filter_map(|(node_idx, node)| match node {
+            MastNode::Call(call_node) => Some((
+                MastNodeId::new_unchecked(node_idx as u32),
+                call_node.callee(),
+                call_node.is_syscall(),
+            )),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the IDs of the nodes reachable from `root` in post-order, i.e.,
+    /// each node is yielded after all of its children, and the children of a node are visited in
+    /// the order returned by [`MastNode::children()`].
+    ///
+    /// Each node is yielded exactly once, even if it is shared by several parents: a shared node
+    /// is yielded when it is first reached, and thus before any of its parents.
+    ///
+    /// # Panics
+    /// Panics if `root` or any node reachable from it is not in this forest.
+    pub fn iter_postorder(&self, root: MastNodeId) -> impl Iterator<Item = MastNodeId> {
+        let mut visited = BTreeSet::new();
+        let mut postorder = Vec::new();
+
+        // the flag indicates whether the children of the node have already been visited
+        let mut stack = vec![(root, false)];
+        while let Some((node_id, children_visited)) = stack.pop() {
+            if children_visited {
+                postorder.push(node_id);
+            } else if visited.insert(node_id) {
+                stack.push((node_id, true));
+                // children are pushed in reverse order so that they are popped in order
+                stack
+                    .extend(self[node_id].children().into_iter().rev().map(|child| (child, false)));
+            }
+        }
+
+        postorder.into_iter()
+    }
+
+    /// Checks that this MAST forest is well-formed, i.e., that all child node IDs refer to nodes in
+    /// this forest and that no node can be reached from itself.
+    ///
+    /// MAST forests built through the methods of [MastForest] are always well-formed; however, this
+    /// is not guaranteed for forests obtained from untrusted sources (e.g., deserialized ones).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A child of a node is not in this forest.
+    /// - This forest contains a cycle.
+    pub fn validate_acyclic(&self) -> Result<(), MastForestError> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum VisitState {
+            NotVisited,
+            InProgress,
+            Done,
+        }
+
+        let mut states = vec![VisitState::NotVisited; self.nodes.len()];
+        for start_idx in 0..self.nodes.len() {
+            if states[start_idx] != VisitState::NotVisited {
+                continue;
+            }
+
+            // the flag indicates whether the children of the node have already been visited
+            let mut stack = vec![(MastNodeId::new_unchecked(start_idx as u32), false)];
+            while let Some((node_id, children_visited)) = stack.pop() {
+                let node_idx = node_id.as_usize();
+                if children_visited {
+                    states[node_idx] = VisitState::Done;
+                    continue;
+                }
+                if states[node_idx] != VisitState::NotVisited {
+                    continue;
+                }
+
+                states[node_idx] = VisitState::InProgress;
+                stack.push((node_id, true));
+                for child_id in self[node_id].children() {
+                    match states.get(child_id.as_usize()) {
+                        None => {
+                            return Err(MastForestError::NodeIdOverflow(child_id, self.nodes.len()))
+                        },
+                        // nodes in progress are the ancestors of the current node
+                        Some(VisitState::InProgress) => {
+                            return Err(MastForestError::NodeCycle(child_id))
+                        },
+                        Some(VisitState::NotVisited) => stack.push((child_id, false)),
+                        Some(VisitState::Done) => (),
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a commitment to the executable content of this MAST forest.
+    ///
+    /// The commitment is computed by hashing the digests of all nodes in the order of their IDs,
+    /// the IDs of all procedure roots, and the entries of the advice map in key order (each entry
+    /// encoded as its key, the number of values, and the values), and merging the three results.
+    /// It can be used, for example, to key a cache of compiled artifacts.
+    ///
+    /// Decorators are not included in the commitment since they do not affect the digests of the
+    /// nodes. Thus, two forests with the same nodes which differ only in the decorators attached
+    /// to these nodes have the same content hash. Note, however, that this does not hold for
+    /// forests assembled from the same source in debug and release mode: the assembler merges
+    /// identical nodes only if their decorators are identical as well, and so a forest assembled
+    /// in debug mode may contain more nodes, and have a different content hash, than the one
+    /// assembled in release mode, even though the MAST roots of their procedures are the same.
+    pub fn content_hash(&self) -> RpoDigest {
+        let node_elements: Vec<Felt> = self
+            .nodes
+            .iter()
+            .flat_map(|node| node.digest().as_elements().to_vec())
+            .collect();
+        let root_elements: Vec<Felt> =
+            self.roots.iter().map(|root_id| Felt::from(root_id.as_u32())).collect();
+        let advice_elements: Vec<Felt> = self
+            .advice_map
+            .iter()
+            .flat_map(|(key, values)| {
+                key.as_elements()
+                    .iter()
+                    .copied()
+                    .chain([Felt::new(values.len() as u64)])
+                    .chain(values.iter().copied())
+            })
+            .collect();
+
+        let nodes_and_roots = hasher::merge(&[
+            hasher::hash_elements(&node_elements),
+            hasher::hash_elements(&root_elements),
+        ]);
+        hasher::merge(&[nodes_and_roots, hasher::hash_elements(&advice_elements)])
+    }
 }
 
 impl Index<MastNodeId> for MastForest {
@@ -669,7 +862,7 @@ impl Serializable for DecoratorId {
 // ================================================================================================
 
 /// Represents the types of errors that can occur when dealing with MAST forest.
-#[derive(Debug, thiserror::Error, PartialEq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum MastForestError {
     #[error(
         "invalid decorator count: MAST forest exceeds the maximum of {} decorators",
@@ -681,7 +874,7 @@ pub enum MastForestError {
         MastForest::MAX_NODES
     )]
     TooManyNodes,
-    #[error("node id: {0} is greater than or equal to forest length: {1}")]
+    #[error("node id {0} out of range for forest of {1} nodes")]
     NodeIdOverflow(MastNodeId, usize),
     #[error("decorator id: {0} is greater than or equal to decorator count: {1}")]
     DecoratorIdOverflow(DecoratorId, usize),
@@ -689,4 +882,8 @@ pub enum MastForestError {
     EmptyBasicBlock,
     #[error("decorator root of child with node id {0} is missing but required for fingerprint computation")]
     ChildFingerprintMissing(MastNodeId),
+    #[error("advice map key {0} already exists with a different value when merging forests")]
+    AdviceMapKeyCollisionOnMerge(RpoDigest),
+    #[error("node id: {0} is reachable from itself")]
+    NodeCycle(MastNodeId),
 }
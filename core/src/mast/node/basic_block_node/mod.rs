@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use core::{fmt, mem};
+use core::{fmt, mem, ops::Range};
 
 use miden_crypto::{hash::rpo::RpoDigest, Felt, ZERO};
 use miden_formatting::prettier::PrettyPrint;
@@ -7,7 +7,7 @@ use miden_formatting::prettier::PrettyPrint;
 use crate::{
     chiplets::hasher,
     mast::{DecoratorId, MastForest, MastForestError},
-    DecoratorIterator, DecoratorList, Operation,
+    AssemblyOp, Decorator, DecoratorIterator, DecoratorList, Operation,
 };
 
 mod op_batch;
@@ -188,6 +188,26 @@ impl BasicBlockNode {
         self.op_batches.iter().flat_map(|batch| batch.ops())
     }
 
+    /// Returns an iterator over the `AsmOp` decorators of this basic block, each accompanied by
+    /// the range of indices of the operations it covers, in the order returned by
+    /// [Self::operations].
+    ///
+    /// An `AsmOp` decorator placed before the operation at index `i` covers the
+    /// [AssemblyOp::num_cycles] operations starting at index `i`.
+    pub fn asm_op_ranges<'a>(
+        &'a self,
+        mast_forest: &'a MastForest,
+    ) -> impl Iterator<Item = (Range<usize>, &'a AssemblyOp)> + 'a {
+        self.decorators.iter().filter_map(|&(op_idx, decorator_id)| {
+            match &mast_forest[decorator_id] {
+                Decorator::AsmOp(assembly_op) => {
+                    Some((op_idx..op_idx + assembly_op.num_cycles() as usize, assembly_op))
+                },
+                _ => None,
+            }
+        })
+    }
+
     /// Returns the total number of operations and decorators in this basic block.
     pub fn num_operations_and_decorators(&self) -> u32 {
         let num_ops: usize = self.num_operations() as usize;
@@ -252,18 +272,19 @@ impl PrettyPrint for BasicBlockNodePrettyPrint<'_> {
     fn render(&self) -> crate::prettier::Document {
         use crate::prettier::*;
 
+        let items: Vec<Document> = self
+            .block_node
+            .iter()
+            .map(|op_or_dec| match op_or_dec {
+                OperationOrDecorator::Operation(op) => op.render(),
+                OperationOrDecorator::Decorator(&decorator_id) => self.mast_forest[decorator_id].render(),
+            })
+            .collect();
+
         // e.g. `basic_block a b c end`
         let single_line = const_text("basic_block")
             + const_text(" ")
-            + self.
-                block_node
-                .iter()
-                .map(|op_or_dec| match op_or_dec {
-                    OperationOrDecorator::Operation(op) => op.render(),
-                    OperationOrDecorator::Decorator(&decorator_id) => self.mast_forest[decorator_id].render(),
-                })
-                .reduce(|acc, doc| acc + const_text(" ") + doc)
-                .unwrap_or_default()
+            + join_documents(items.clone(), || const_text(" "))
             + const_text(" ")
             + const_text("end");
 
@@ -279,15 +300,7 @@ impl PrettyPrint for BasicBlockNodePrettyPrint<'_> {
             4,
             const_text("basic_block")
                 + nl()
-                + self
-                    .block_node
-                    .iter()
-                    .map(|op_or_dec| match op_or_dec {
-                        OperationOrDecorator::Operation(op) => op.render(),
-                        OperationOrDecorator::Decorator(&decorator_id) => self.mast_forest[decorator_id].render(),
-                    })
-                    .reduce(|acc, doc| acc + nl() + doc)
-                    .unwrap_or_default(),
+                + join_documents(items, nl),
         ) + nl()
             + const_text("end");
 
@@ -417,6 +430,31 @@ fn batch_ops(ops: Vec<Operation>) -> Vec<OpBatch> {
     batches
 }
 
+/// Concatenates the documents, separated by the document returned by `separator`.
+///
+/// Basic blocks can contain thousands of operations, and concatenating their renderings one by one
+/// would result in a document nested as deeply as there are operations, which overflows the stack
+/// when the document is printed or dropped. Instead, adjacent documents are concatenated pairwise,
+/// which keeps the nesting depth logarithmic in the number of documents.
+fn join_documents(
+    mut docs: Vec<crate::prettier::Document>,
+    separator: fn() -> crate::prettier::Document,
+) -> crate::prettier::Document {
+    while docs.len() > 1 {
+        let mut joined = Vec::with_capacity(docs.len().div_ceil(2));
+        let mut docs_iter = docs.into_iter();
+        while let Some(left) = docs_iter.next() {
+            joined.push(match docs_iter.next() {
+                Some(right) => left + separator() + right,
+                None => left,
+            });
+        }
+        docs = joined;
+    }
+
+    docs.pop().unwrap_or_default()
+}
+
 /// Checks if a given decorators list is valid (only checked in debug mode)
 /// - Assert the decorator list is in ascending order.
 /// - Assert the last op index in decorator list is less than or equal to the number of operations.
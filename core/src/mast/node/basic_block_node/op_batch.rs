@@ -19,10 +19,30 @@ pub struct OpBatch {
 
 impl OpBatch {
     /// Returns a list of operations contained in this batch.
+    ///
+    /// Operations are listed in the order in which they are executed. The list does not include
+    /// any padding: unused operation slots in a group are implicitly filled with `NOOP`s (i.e.,
+    /// opcode 0) when the group is encoded into a field element, but are not listed here.
     pub fn ops(&self) -> &[Operation] {
         &self.ops
     }
 
+    /// Returns an iterator over the operations of each operation group in this batch, in order.
+    ///
+    /// Groups which contain immediate values (rather than operations) are skipped. As with
+    /// [OpBatch::ops()], `NOOP` padding of partially filled groups is not included.
+    pub fn op_groups(&self) -> impl Iterator<Item = &[Operation]> {
+        let mut start = 0;
+        self.op_counts[..self.num_groups]
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(move |&count| {
+                let group = &self.ops[start..start + count];
+                start += count;
+                group
+            })
+    }
+
     /// Returns a list of operation groups contained in this batch.
     ///
     /// Each group is represented by a single field element.
@@ -293,6 +293,63 @@ fn batch_ops() {
     assert_eq!(hasher::hash_elements(&all_groups), hash);
 }
 
+#[test]
+fn op_batch_op_groups() {
+    // --- 9 operations fill exactly one group ------------------------------------------------
+    let ops = vec![
+        Operation::Add,
+        Operation::Mul,
+        Operation::Neg,
+        Operation::Inv,
+        Operation::Incr,
+        Operation::And,
+        Operation::Or,
+        Operation::Not,
+        Operation::Swap,
+    ];
+    let (batches, _) = super::batch_and_hash_ops(ops.clone());
+    assert_eq!(1, batches.len());
+
+    let batch = &batches[0];
+    assert_eq!(ops, batch.ops());
+    let groups: Vec<&[Operation]> = batch.op_groups().collect();
+    assert_eq!(vec![ops.as_slice()], groups);
+
+    // --- groups with immediate values are skipped -------------------------------------------
+    let mut ops = ops;
+    ops.push(Operation::Push(Felt::new(7)));
+    ops.push(Operation::Drop);
+    let (batches, _) = super::batch_and_hash_ops(ops.clone());
+    assert_eq!(1, batches.len());
+
+    let batch = &batches[0];
+    assert_eq!(3, batch.num_groups());
+    let groups: Vec<&[Operation]> = batch.op_groups().collect();
+    assert_eq!(vec![&ops[..9], &ops[9..]], groups);
+}
+
+#[test]
+fn num_op_groups_and_batches() {
+    // (number of operations, expected number of op groups, expected number of op batches)
+    let cases = [
+        // a single group
+        (1, 1, 1),
+        (GROUP_SIZE, 1, 1),
+        // 3 groups are rounded up to the next power of two
+        (2 * GROUP_SIZE + 1, 4, 1),
+        // a full batch
+        (BATCH_SIZE * GROUP_SIZE, BATCH_SIZE, 1),
+        // a full batch followed by a batch with 3 groups
+        ((BATCH_SIZE + 3) * GROUP_SIZE, BATCH_SIZE + 4, 2),
+    ];
+
+    for (num_ops, num_op_groups, num_op_batches) in cases {
+        let block = BasicBlockNode::new(vec![Operation::Add; num_ops], None).unwrap();
+        assert_eq!(num_op_groups, block.num_op_groups(), "{num_ops} operations");
+        assert_eq!(num_op_batches, block.num_op_batches(), "{num_ops} operations");
+    }
+}
+
 #[test]
 fn operation_or_decorator_iterator() {
     let mut mast_forest = MastForest::new();
@@ -331,6 +388,31 @@ fn operation_or_decorator_iterator() {
     assert_eq!(iterator.next(), None);
 }
 
+#[test]
+fn decorator_iterator_peek() {
+    let decorators: DecoratorList =
+        vec![(0, DecoratorId(0)), (0, DecoratorId(1)), (2, DecoratorId(2))];
+    let mut iterator = DecoratorIterator::new(&decorators);
+
+    // peeking does not advance the iterator
+    assert_eq!(iterator.peek_position(), Some(0));
+    assert_eq!(iterator.peek(), Some(&DecoratorId(0)));
+    assert_eq!(iterator.peek(), Some(&DecoratorId(0)));
+
+    assert_eq!(iterator.next_filtered(0), Some(&DecoratorId(0)));
+    assert_eq!(iterator.peek_position(), Some(0));
+    assert_eq!(iterator.next_filtered(0), Some(&DecoratorId(1)));
+
+    // the next decorator does not apply at position 1
+    assert_eq!(iterator.peek_position(), Some(2));
+    assert_eq!(iterator.next_filtered(1), None);
+    assert_eq!(iterator.peek(), Some(&DecoratorId(2)));
+
+    assert_eq!(iterator.next(), Some(&DecoratorId(2)));
+    assert_eq!(iterator.peek_position(), None);
+    assert_eq!(iterator.peek(), None);
+}
+
 // TEST HELPERS
 // --------------------------------------------------------------------------------------------
 
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use miden_crypto::{hash::rpo::RpoDigest, Felt};
-use miden_formatting::prettier::{const_text, nl, Document, PrettyPrint};
+use miden_formatting::prettier::{const_text, display, nl, Document, PrettyPrint};
 
 use crate::{
     mast::{DecoratorId, MastForest},
@@ -13,13 +13,30 @@ use crate::{
 // ================================================================================================
 
 /// A Dyn node specifies that the node to be executed next is defined dynamically via the stack.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// A Dyn node can optionally be annotated with the MAST roots of the procedures it may invoke, if
+/// these are known from the surrounding context (e.g., from static analysis). These possible
+/// targets are informational only: they do not affect the digest of the node, are not enforced
+/// during execution, are not serialized, and are ignored when comparing nodes for equality.
+#[derive(Debug, Clone)]
 pub struct DynNode {
     is_dyncall: bool,
+    possible_targets: Vec<RpoDigest>,
     before_enter: Vec<DecoratorId>,
     after_exit: Vec<DecoratorId>,
 }
 
+/// Possible targets are excluded from equality since they are not part of the serialized node.
+impl PartialEq for DynNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_dyncall == other.is_dyncall
+            && self.before_enter == other.before_enter
+            && self.after_exit == other.after_exit
+    }
+}
+
+impl Eq for DynNode {}
+
 /// Constants
 impl DynNode {
     /// The domain of the Dyn block (used for control block hashing).
@@ -35,6 +52,7 @@ impl DynNode {
     pub fn new_dyn() -> Self {
         Self {
             is_dyncall: false,
+            possible_targets: Vec::new(),
             before_enter: Vec::new(),
             after_exit: Vec::new(),
         }
@@ -44,6 +62,7 @@ impl DynNode {
     pub fn new_dyncall() -> Self {
         Self {
             is_dyncall: true,
+            possible_targets: Vec::new(),
             before_enter: Vec::new(),
             after_exit: Vec::new(),
         }
@@ -54,6 +73,13 @@ impl DynNode {
         self.is_dyncall
     }
 
+    /// Returns the MAST roots of the procedures this node is known to possibly invoke.
+    ///
+    /// An empty list means that the possible targets of this node are unknown.
+    pub fn possible_targets(&self) -> &[RpoDigest] {
+        &self.possible_targets
+    }
+
     /// Returns the domain of this dyn node.
     pub fn domain(&self) -> Felt {
         if self.is_dyncall() {
@@ -105,6 +131,13 @@ impl DynNode {
 
 /// Mutators
 impl DynNode {
+    /// Sets the MAST roots of the procedures this node may invoke.
+    ///
+    /// This does not change the digest of the node.
+    pub fn set_possible_targets(&mut self, possible_targets: Vec<RpoDigest>) {
+        self.possible_targets = possible_targets;
+    }
+
     /// Sets the list of decorators to be executed before this node.
     pub fn set_before_enter(&mut self, decorator_ids: Vec<DecoratorId>) {
         self.before_enter = decorator_ids;
@@ -174,6 +207,17 @@ impl DynNodePrettyPrint<'_> {
     fn multi_line_post_decorators(&self) -> Document {
         self.concatenate_decorators(self.node.after_exit(), nl(), Document::Empty)
     }
+
+    /// Renders the possible targets of the node as a comment, if any are known.
+    fn possible_targets(&self) -> Document {
+        self.node
+            .possible_targets()
+            .iter()
+            .map(display)
+            .reduce(|acc, doc| acc + const_text(", ") + doc)
+            .map(|targets| const_text(" # possible targets: ") + targets)
+            .unwrap_or_default()
+    }
 }
 
 impl crate::prettier::PrettyPrint for DynNodePrettyPrint<'_> {
@@ -184,11 +228,16 @@ impl crate::prettier::PrettyPrint for DynNodePrettyPrint<'_> {
             const_text("dyn")
         };
 
+        // the possible targets are rendered as a trailing comment, so they must be placed at the
+        // end of the line
         let single_line = self.single_line_pre_decorators()
             + dyn_text.clone()
-            + self.single_line_post_decorators();
-        let multi_line =
-            self.multi_line_pre_decorators() + dyn_text + self.multi_line_post_decorators();
+            + self.single_line_post_decorators()
+            + self.possible_targets();
+        let multi_line = self.multi_line_pre_decorators()
+            + dyn_text
+            + self.possible_targets()
+            + self.multi_line_post_decorators();
 
         single_line | multi_line
     }
@@ -205,6 +254,8 @@ impl fmt::Display for DynNodePrettyPrint<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use miden_crypto::hash::rpo::Rpo256;
 
     use super::*;
@@ -229,4 +280,53 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    pub fn test_dyn_node_possible_targets() {
+        let targets = vec![
+            RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+            RpoDigest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+        ];
+
+        for mut node in [DynNode::new_dyn(), DynNode::new_dyncall()] {
+            let digest = node.digest();
+            assert!(node.possible_targets().is_empty());
+
+            node.set_possible_targets(targets.clone());
+            assert_eq!(node.possible_targets(), targets.as_slice());
+            assert_eq!(node.digest(), digest);
+
+            let rendered = node.to_display(&MastForest::new()).to_string();
+            assert!(rendered.contains("# possible targets: "), "{rendered}");
+            for target in targets.iter() {
+                assert!(rendered.contains(&target.to_string()), "{rendered}");
+            }
+        }
+
+        let rendered = DynNode::new_dyn().to_display(&MastForest::new()).to_string();
+        assert_eq!(rendered, "dyn");
+    }
+
+    #[test]
+    pub fn test_dyn_node_possible_targets_serialization() {
+        use crate::{
+            mast::MastNode,
+            utils::{Deserializable, Serializable},
+        };
+
+        let target = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        let mut forest = MastForest::new();
+        let mut node = DynNode::new_dyncall();
+        node.set_possible_targets(vec![target]);
+        let node_id = forest.add_node(MastNode::Dyn(node)).unwrap();
+        forest.make_root(node_id);
+
+        let deserialized = MastForest::read_from_bytes(&forest.to_bytes()).unwrap();
+        match &deserialized[node_id] {
+            MastNode::Dyn(node) => assert!(node.possible_targets().is_empty()),
+            node => panic!("expected a dyn node, found {node:?}"),
+        }
+        assert_eq!(deserialized, forest);
+    }
 }
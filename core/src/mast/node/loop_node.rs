@@ -19,10 +19,16 @@ use crate::{
 /// The loop is exited when at the end of executing the loop body the top of the stack is `0``.
 /// If the top of the stack is neither `0` nor `1` when the condition is checked, the execution
 /// fails.
+///
+/// A Loop node can optionally be annotated as statically never entered, if it is known from the
+/// surrounding context that the condition is always `0` when the loop is reached. This annotation
+/// is informational only: it does not affect the digest of the node and is not enforced during
+/// execution, but it is preserved when the node is serialized.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LoopNode {
     body: MastNodeId,
     digest: RpoDigest,
+    statically_never: bool,
     before_enter: Vec<DecoratorId>,
     after_exit: Vec<DecoratorId>,
 }
@@ -49,6 +55,7 @@ impl LoopNode {
         Ok(Self {
             body,
             digest,
+            statically_never: false,
             before_enter: Vec::new(),
             after_exit: Vec::new(),
         })
@@ -60,6 +67,7 @@ impl LoopNode {
         Self {
             body,
             digest,
+            statically_never: false,
             before_enter: Vec::new(),
             after_exit: Vec::new(),
         }
@@ -86,6 +94,12 @@ impl LoopNode {
         self.body
     }
 
+    /// Returns true if this loop is known to never be entered, i.e., if its condition is known to
+    /// always be `0` when the loop is reached.
+    pub fn is_statically_never(&self) -> bool {
+        self.statically_never
+    }
+
     /// Returns the decorators to be executed before this node is executed.
     pub fn before_enter(&self) -> &[DecoratorId] {
         &self.before_enter
@@ -99,6 +113,13 @@ impl LoopNode {
 
 /// Mutators
 impl LoopNode {
+    /// Sets whether this loop is known to never be entered.
+    ///
+    /// This does not change the digest of the node.
+    pub fn set_statically_never(&mut self, statically_never: bool) {
+        self.statically_never = statically_never;
+    }
+
     /// Sets the list of decorators to be executed before this node.
     pub fn set_before_enter(&mut self, decorator_ids: Vec<DecoratorId>) {
         self.before_enter = decorator_ids;
@@ -167,8 +188,13 @@ impl crate::prettier::PrettyPrint for LoopNodePrettyPrint<'_> {
 
         let loop_body = self.mast_forest[self.loop_node.body].to_pretty_print(self.mast_forest);
 
+        let mut loop_header = const_text("while.true");
+        if self.loop_node.is_statically_never() {
+            loop_header += const_text(" # statically never entered");
+        }
+
         pre_decorators
-            + indent(4, const_text("while.true") + nl() + loop_body.render())
+            + indent(4, loop_header + nl() + loop_body.render())
             + nl()
             + const_text("end")
             + post_decorators
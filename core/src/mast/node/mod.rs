@@ -22,6 +22,7 @@ pub use join_node::JoinNode;
 mod split_node;
 use miden_crypto::{hash::rpo::RpoDigest, Felt};
 use miden_formatting::prettier::{Document, PrettyPrint};
+use smallvec::{smallvec, SmallVec};
 pub use split_node::SplitNode;
 
 mod loop_node;
@@ -134,6 +135,31 @@ impl MastNode {
         matches!(self, Self::Block(_))
     }
 
+    /// Returns true if this node is a Call node, including a Call node representing a syscall.
+    pub fn is_call(&self) -> bool {
+        matches!(self, MastNode::Call(_))
+    }
+
+    /// Returns true if this node is a Call node representing a syscall.
+    pub fn is_syscall(&self) -> bool {
+        matches!(self, MastNode::Call(call_node) if call_node.is_syscall())
+    }
+
+    /// Returns the IDs of the children of this node.
+    ///
+    /// Control flow nodes return their children in execution order (e.g., the first and second
+    /// child of a Join node, or the `true` and `false` branches of a Split node), while basic
+    /// blocks, Dyn nodes and external nodes have no children.
+    pub fn children(&self) -> SmallVec<[MastNodeId; 2]> {
+        match self {
+            MastNode::Join(join_node) => smallvec![join_node.first(), join_node.second()],
+            MastNode::Split(split_node) => smallvec![split_node.on_true(), split_node.on_false()],
+            MastNode::Loop(loop_node) => smallvec![loop_node.body()],
+            MastNode::Call(call_node) => smallvec![call_node.callee()],
+            MastNode::Block(_) | MastNode::Dyn(_) | MastNode::External(_) => SmallVec::new(),
+        }
+    }
+
     /// Returns the inner basic block node if the [`MastNode`] wraps a [`BasicBlockNode`]; `None`
     /// otherwise.
     pub fn get_basic_block(&self) -> Option<&BasicBlockNode> {
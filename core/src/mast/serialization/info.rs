@@ -75,9 +75,10 @@ impl MastNodeInfo {
                 let split = SplitNode::new_unsafe([if_branch, else_branch], self.digest);
                 Ok(MastNode::Split(split))
             },
-            MastNodeType::Loop { body_id } => {
+            MastNodeType::Loop { body_id, statically_never } => {
                 let body_id = MastNodeId::from_u32_with_node_count(body_id, node_count)?;
-                let loop_node = LoopNode::new_unsafe(body_id, self.digest);
+                let mut loop_node = LoopNode::new_unsafe(body_id, self.digest);
+                loop_node.set_statically_never(statically_never);
                 Ok(MastNode::Loop(loop_node))
             },
             MastNodeType::Call { callee_id } => {
@@ -128,6 +129,10 @@ const DYN: u8 = 6;
 const DYNCALL: u8 = 7;
 const EXTERNAL: u8 = 8;
 
+/// The bit of the payload of a `Loop` node type which is set if the loop is annotated as
+/// statically never entered; the body id is stored in the lower 32 bits.
+const LOOP_STATICALLY_NEVER_FLAG: u64 = 1 << 32;
+
 /// Represents the variant of a [`MastNode`], as well as any additional data. For example, for more
 /// efficient decoding, and because of the frequency with which these node types appear, we directly
 /// represent the child indices for `Join`, `Split`, and `Loop`, `Call` and `SysCall` inline.
@@ -147,6 +152,7 @@ pub enum MastNodeType {
     } = SPLIT,
     Loop {
         body_id: u32,
+        statically_never: bool,
     } = LOOP,
     Block {
         // offset of operations in node data
@@ -188,7 +194,10 @@ impl MastNodeType {
                 if_branch_id: split_node.on_true().0,
                 else_branch_id: split_node.on_false().0,
             },
-            Loop(loop_node) => Self::Loop { body_id: loop_node.body().0 },
+            Loop(loop_node) => Self::Loop {
+                body_id: loop_node.body().0,
+                statically_never: loop_node.is_statically_never(),
+            },
             Call(call_node) => {
                 if call_node.is_syscall() {
                     Self::SysCall { callee_id: call_node.callee().0 }
@@ -222,7 +231,14 @@ impl Serializable for MastNodeType {
                 if_branch_id: if_branch,
                 else_branch_id: else_branch,
             } => Self::encode_u32_pair(if_branch, else_branch),
-            MastNodeType::Loop { body_id: body } => Self::encode_u32_payload(body),
+            MastNodeType::Loop { body_id: body, statically_never } => {
+                let flag = if statically_never {
+                    LOOP_STATICALLY_NEVER_FLAG
+                } else {
+                    0
+                };
+                Self::encode_u32_payload(body) | flag
+            },
             MastNodeType::Block { ops_offset, decorator_list_offset } => {
                 Self::encode_u32_pair(ops_offset, decorator_list_offset)
             },
@@ -296,8 +312,9 @@ impl Deserializable for MastNodeType {
                 Ok(Self::Split { if_branch_id, else_branch_id })
             },
             LOOP => {
-                let body_id = Self::decode_u32_payload(payload)?;
-                Ok(Self::Loop { body_id })
+                let statically_never = payload & LOOP_STATICALLY_NEVER_FLAG != 0;
+                let body_id = Self::decode_u32_payload(payload & !LOOP_STATICALLY_NEVER_FLAG)?;
+                Ok(Self::Loop { body_id, statically_never })
             },
             BLOCK => {
                 let (ops_offset, decorator_list_offset) = Self::decode_u32_pair(payload);
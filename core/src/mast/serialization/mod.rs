@@ -11,6 +11,9 @@
 //! (procedure roots)
 //! - procedure roots (`Vec<MastNodeId>`)
 //!
+//! (advice map)
+//! - advice map (`AdviceMap`)
+//!
 //! (raw data)
 //! - Decorator data
 //! - Node data
@@ -23,6 +26,10 @@
 //! (before enter and after exit decorators)
 //! - before enter decorators (`Vec<(MastNodeId, Vec<DecoratorId>)>`)
 //! - after exit decorators (`Vec<(MastNodeId, Vec<DecoratorId>)>`)
+//!
+//! The "statically never entered" annotation of a loop node is stored in bit 32 of the payload of
+//! its node type, next to the id of the loop body; forests serialized before this annotation was
+//! introduced have this bit unset, and thus are still read correctly.
 
 use alloc::vec::Vec;
 
@@ -31,6 +38,7 @@ use string_table::{StringTable, StringTableBuilder};
 use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{DecoratorId, MastForest, MastNode, MastNodeId};
+use crate::AdviceMap;
 
 mod decorator;
 
@@ -71,7 +79,7 @@ const MAGIC: &[u8; 5] = b"MAST\0";
 /// If future modifications are made to this format, the version should be incremented by 1. A
 /// version of `[255, 255, 255]` is reserved for future extensions that require extending the
 /// version field itself, but should be considered invalid for now.
-const VERSION: [u8; 3] = [0, 0, 0];
+const VERSION: [u8; 3] = [0, 0, 1];
 
 // MAST FOREST SERIALIZATION/DESERIALIZATION
 // ================================================================================================
@@ -98,6 +106,9 @@ impl Serializable for MastForest {
         let roots: Vec<u32> = self.roots.iter().map(u32::from).collect();
         roots.write_into(target);
 
+        // advice map
+        self.advice_map.write_into(target);
+
         // decorators
         let decorator_infos: Vec<DecoratorInfo> = self
             .decorators
@@ -184,6 +195,7 @@ impl Deserializable for MastForest {
         let decorator_count = source.read_usize()?;
         let node_count = source.read_usize()?;
         let roots: Vec<u32> = Deserializable::read_from(source)?;
+        let advice_map = AdviceMap::read_from(source)?;
         let decorator_data: Vec<u8> = Deserializable::read_from(source)?;
         let node_data: Vec<u8> = Deserializable::read_from(source)?;
         let string_table: StringTable = Deserializable::read_from(source)?;
@@ -229,6 +241,8 @@ impl Deserializable for MastForest {
                 mast_forest.make_root(root);
             }
 
+            mast_forest.advice_map = advice_map;
+
             mast_forest
         };
 
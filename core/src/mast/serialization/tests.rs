@@ -372,6 +372,46 @@ fn mast_forest_serialize_deserialize_with_child_ids_exceeding_parent_id() {
     MastForest::read_from_bytes(&forest.to_bytes()).unwrap();
 }
 
+/// Test that the advice map of a forest survives a serialization round trip.
+#[test]
+fn mast_forest_serialize_deserialize_advice_map() {
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Operation::Add], None).unwrap();
+    forest.make_root(block);
+    forest
+        .advice_map_mut()
+        .insert(RpoDigest::default(), vec![Felt::new(1), Felt::new(2)]);
+
+    let deserialized = MastForest::read_from_bytes(&forest.to_bytes()).unwrap();
+    assert_eq!(deserialized.advice_map(), forest.advice_map());
+    assert_eq!(deserialized, forest);
+}
+
+#[test]
+fn mast_forest_serialize_deserialize_loop_statically_never() {
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Operation::Add], None).unwrap();
+    let never_entered = forest.add_loop(block).unwrap();
+    let MastNode::Loop(loop_node) = &mut forest[never_entered] else {
+        panic!("expected a loop node");
+    };
+    loop_node.set_statically_never(true);
+    let entered = forest.add_loop(block).unwrap();
+    forest.make_root(never_entered);
+    forest.make_root(entered);
+
+    let deserialized = MastForest::read_from_bytes(&forest.to_bytes()).unwrap();
+    assert_eq!(deserialized, forest);
+    let MastNode::Loop(loop_node) = &deserialized[never_entered] else {
+        panic!("expected a loop node");
+    };
+    assert!(loop_node.is_statically_never());
+    let MastNode::Loop(loop_node) = &deserialized[entered] else {
+        panic!("expected a loop node");
+    };
+    assert!(!loop_node.is_statically_never());
+}
+
 /// Test that a forest with a node whose referenced index is >= the max number of nodes in
 /// the forest returns an error during deserialization.
 #[test]
@@ -399,6 +439,34 @@ fn mast_forest_serialize_deserialize_with_overflowing_ids_fails() {
     );
 }
 
+/// Test that a buffer with an unknown format version is rejected before any of the forest data is
+/// interpreted.
+#[test]
+fn mast_forest_deserialize_with_unsupported_version_fails() {
+    let mut forest = MastForest::new();
+    let block_id = forest.add_block(vec![Operation::Add], None).unwrap();
+    forest.make_root(block_id);
+
+    let mut bytes = forest.to_bytes();
+    assert_eq!(&bytes[..MAGIC.len()], MAGIC);
+    assert_eq!(&bytes[MAGIC.len()..MAGIC.len() + VERSION.len()], &VERSION);
+
+    // bump the version past the supported one
+    bytes[MAGIC.len() + VERSION.len() - 1] = VERSION[2] + 1;
+    assert_matches!(
+        MastForest::read_from_bytes(&bytes),
+        Err(DeserializationError::InvalidValue(msg)) if msg.contains("Unsupported version")
+    );
+
+    // corrupt the magic bytes
+    let mut bytes = forest.to_bytes();
+    bytes[0] = b'X';
+    assert_matches!(
+        MastForest::read_from_bytes(&bytes),
+        Err(DeserializationError::InvalidValue(msg)) if msg.contains("Invalid magic bytes")
+    );
+}
+
 #[test]
 fn mast_forest_invalid_node_id() {
     // Hydrate a forest smaller than the second
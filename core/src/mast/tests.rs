@@ -1,11 +1,18 @@
-use alloc::vec::Vec;
+use alloc::{format, string::ToString, vec::Vec};
 
 use miden_crypto::{hash::rpo::RpoDigest, Felt};
 use proptest::prelude::*;
 use rand_utils::prng_array;
 use winter_utils::{Deserializable, Serializable};
 
-use crate::{chiplets::hasher, mast::DynNode, Kernel, ProgramInfo, Word};
+use crate::{
+    assert_matches,
+    chiplets::hasher,
+    mast::{
+        DecoratorId, DynNode, JoinNode, LoopNode, MastForest, MastForestError, MastNode, MastNodeId,
+    },
+    Decorator, Kernel, Operation, ProgramInfo, Word,
+};
 
 #[test]
 fn dyn_hash_is_correct() {
@@ -14,6 +21,257 @@ fn dyn_hash_is_correct() {
     assert_eq!(expected_constant, DynNode::new_dyn().digest());
 }
 
+#[test]
+fn mast_forest_content_hash_ignores_decorators() {
+    let build_forest = |with_decorators: bool| {
+        let mut forest = MastForest::new();
+        let decorators = if with_decorators {
+            let trace_id = forest.add_decorator(Decorator::Trace(1)).unwrap();
+            Some(vec![(0, trace_id)])
+        } else {
+            None
+        };
+        let block_id = forest.add_block(vec![Operation::Add, Operation::Mul], decorators).unwrap();
+        if with_decorators {
+            let trace_id = forest.add_decorator(Decorator::Trace(2)).unwrap();
+            forest.set_after_exit(block_id, vec![trace_id]);
+        }
+        forest.make_root(block_id);
+        forest
+    };
+
+    let plain = build_forest(false);
+    let decorated = build_forest(true);
+    assert_ne!(plain, decorated);
+    assert_eq!(plain.content_hash(), decorated.content_hash());
+}
+
+#[test]
+fn mast_forest_content_hash_depends_on_executable_content() {
+    let mut forest = MastForest::new();
+    let block_id = forest.add_block(vec![Operation::Add, Operation::Mul], None).unwrap();
+    forest.make_root(block_id);
+
+    // different operations result in a different hash
+    let mut other_ops = MastForest::new();
+    let other_block_id = other_ops.add_block(vec![Operation::Mul, Operation::Add], None).unwrap();
+    other_ops.make_root(other_block_id);
+    assert_ne!(forest.content_hash(), other_ops.content_hash());
+
+    // the same nodes with a different set of procedure roots result in a different hash
+    let mut extra_root = forest.clone();
+    let loop_id = extra_root.add_loop(block_id).unwrap();
+    let mut no_extra_root = extra_root.clone();
+    extra_root.make_root(loop_id);
+    assert_ne!(extra_root.content_hash(), no_extra_root.content_hash());
+
+    // the hash is deterministic
+    no_extra_root.make_root(loop_id);
+    assert_eq!(extra_root.content_hash(), no_extra_root.content_hash());
+}
+
+#[test]
+fn mast_forest_content_hash_depends_on_advice_map() {
+    let mut forest = MastForest::new();
+    let block_id = forest.add_block(vec![Operation::Add, Operation::Mul], None).unwrap();
+    forest.make_root(block_id);
+
+    let key = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let mut with_advice = forest.clone();
+    with_advice.advice_map_mut().insert(key, vec![Felt::new(5)]);
+    assert_ne!(forest.content_hash(), with_advice.content_hash());
+
+    // different values under the same key result in a different hash
+    let mut other_values = forest.clone();
+    other_values.advice_map_mut().insert(key, vec![Felt::new(5), Felt::new(6)]);
+    assert_ne!(with_advice.content_hash(), other_values.content_hash());
+
+    // the same entries inserted in a different order result in the same hash
+    let other_key = RpoDigest::new([Felt::new(4), Felt::new(3), Felt::new(2), Felt::new(1)]);
+    let mut forward = with_advice.clone();
+    forward.advice_map_mut().insert(other_key, vec![]);
+    let mut backward = forest.clone();
+    backward.advice_map_mut().insert(other_key, vec![]);
+    backward.advice_map_mut().insert(key, vec![Felt::new(5)]);
+    assert_eq!(forward.content_hash(), backward.content_hash());
+}
+
+#[test]
+fn mast_node_predicates_and_children() {
+    let mut forest = MastForest::new();
+    let block_a = forest.add_block(vec![Operation::Add], None).unwrap();
+    let block_b = forest.add_block(vec![Operation::Mul], None).unwrap();
+    let join = forest.add_join(block_a, block_b).unwrap();
+    let split = forest.add_split(block_b, block_a).unwrap();
+    let loop_id = forest.add_loop(block_a).unwrap();
+    let call = forest.add_call(block_b).unwrap();
+    let syscall = forest.add_syscall(block_a).unwrap();
+    let dyn_id = forest.add_dyn().unwrap();
+    let external = forest.add_external(RpoDigest::default()).unwrap();
+
+    let node = |id| &forest[id];
+
+    assert!(node(block_a).is_basic_block());
+    assert!(node(block_a).children().is_empty());
+
+    assert_eq!(node(join).children().as_slice(), &[block_a, block_b]);
+    assert_eq!(node(split).children().as_slice(), &[block_b, block_a]);
+    assert_eq!(node(loop_id).children().as_slice(), &[block_a]);
+
+    assert!(node(call).is_call());
+    assert!(!node(call).is_syscall());
+    assert_eq!(node(call).children().as_slice(), &[block_b]);
+
+    assert!(node(syscall).is_call());
+    assert!(node(syscall).is_syscall());
+    assert_eq!(node(syscall).children().as_slice(), &[block_a]);
+
+    assert!(node(dyn_id).is_dyn());
+    assert!(node(dyn_id).children().is_empty());
+
+    assert!(node(external).is_external());
+    assert!(node(external).children().is_empty());
+
+    for id in [block_a, join, split, loop_id, dyn_id, external] {
+        assert!(!node(id).is_call());
+        assert!(!node(id).is_syscall());
+    }
+}
+
+#[test]
+fn mast_forest_call_edges() {
+    let mut forest = MastForest::new();
+    let block_a = forest.add_block(vec![Operation::Add], None).unwrap();
+    let block_b = forest.add_block(vec![Operation::Mul], None).unwrap();
+    let call = forest.add_call(block_a).unwrap();
+    let syscall = forest.add_syscall(block_b).unwrap();
+    forest.add_join(call, syscall).unwrap();
+
+    let edges: Vec<_> = forest.call_edges().collect();
+    assert_eq!(edges, [(call, block_a, false), (syscall, block_b, true)]);
+}
+
+#[test]
+fn mast_forest_iter_postorder_visits_shared_nodes_once() {
+    let mut forest = MastForest::new();
+    let block_a = forest.add_block(vec![Operation::Add], None).unwrap();
+    let block_b = forest.add_block(vec![Operation::Mul], None).unwrap();
+    // both `join` and `split` have `block_a` and `block_b` as children
+    let join = forest.add_join(block_a, block_b).unwrap();
+    let split = forest.add_split(block_b, block_a).unwrap();
+    let root = forest.add_join(join, split).unwrap();
+
+    let postorder: Vec<_> = forest.iter_postorder(root).collect();
+    assert_eq!(postorder, [block_a, block_b, join, split, root]);
+
+    // nodes which are not reachable from the root are not visited
+    assert_eq!(forest.iter_postorder(split).collect::<Vec<_>>(), [block_b, block_a, split]);
+}
+
+#[test]
+fn mast_forest_validate_acyclic() {
+    let mut forest = MastForest::new();
+    let block_a = forest.add_block(vec![Operation::Add], None).unwrap();
+    let block_b = forest.add_block(vec![Operation::Mul], None).unwrap();
+    let join = forest.add_join(block_a, block_b).unwrap();
+    let outer_join = forest.add_join(join, block_b).unwrap();
+    assert_eq!(forest.validate_acyclic(), Ok(()));
+
+    // make the inner join node a parent of the outer one
+    forest[join] = MastNode::Join(JoinNode::new([outer_join, block_b], &forest).unwrap());
+    assert_matches!(forest.validate_acyclic(), Err(MastForestError::NodeCycle(_)));
+
+    // a loop whose body is the loop itself
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Operation::Add], None).unwrap();
+    let loop_id = forest.add_loop(block).unwrap();
+    forest[loop_id] = MastNode::Loop(LoopNode::new(loop_id, &forest).unwrap());
+    assert_eq!(forest.validate_acyclic(), Err(MastForestError::NodeCycle(loop_id)));
+}
+
+#[test]
+fn mast_forest_get_node_by_digest() {
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Operation::Add], None).unwrap();
+    let call = forest.add_call(block).unwrap();
+
+    let call_digest = forest[call].digest();
+    assert_eq!(forest.get_node_by_digest(call_digest), Some(&forest[call]));
+    assert_eq!(forest.get_node_by_digest(forest[block].digest()), Some(&forest[block]));
+    assert_eq!(forest.get_node_by_digest(RpoDigest::default()), None);
+}
+
+#[test]
+fn loop_node_statically_never() {
+    let mut forest = MastForest::new();
+    let body = forest.add_block(vec![Operation::Add], None).unwrap();
+    let loop_id = forest.add_loop(body).unwrap();
+
+    let MastNode::Loop(loop_node) = &mut forest[loop_id] else {
+        panic!("expected a loop node");
+    };
+    assert_eq!(loop_node.body(), body);
+    assert!(!loop_node.is_statically_never());
+
+    let digest = loop_node.digest();
+    loop_node.set_statically_never(true);
+    assert!(loop_node.is_statically_never());
+    assert_eq!(loop_node.digest(), digest);
+
+    let rendered = forest[loop_id].to_display(&forest).to_string();
+    assert!(rendered.starts_with("while.true # statically never entered"), "{rendered}");
+}
+
+#[test]
+fn mast_forest_error_messages() {
+    let node_id = MastNodeId::new_unchecked(5);
+    let errors = [
+        (
+            MastForestError::TooManyDecorators,
+            format!("invalid decorator count: MAST forest exceeds the maximum of {} decorators", u32::MAX),
+        ),
+        (
+            MastForestError::TooManyNodes,
+            format!(
+                "invalid node count: MAST forest exceeds the maximum of {} nodes",
+                MastForest::MAX_NODES
+            ),
+        ),
+        (
+            MastForestError::NodeIdOverflow(node_id, 2),
+            "node id MastNodeId(5) out of range for forest of 2 nodes".to_string(),
+        ),
+        (
+            MastForestError::DecoratorIdOverflow(DecoratorId::new_unchecked(3), 1),
+            "decorator id: DecoratorId(3) is greater than or equal to decorator count: 1".to_string(),
+        ),
+        (
+            MastForestError::EmptyBasicBlock,
+            "basic block cannot be created from an empty list of operations".to_string(),
+        ),
+        (
+            MastForestError::ChildFingerprintMissing(node_id),
+            "decorator root of child with node id MastNodeId(5) is missing but required for fingerprint computation".to_string(),
+        ),
+        (
+            MastForestError::AdviceMapKeyCollisionOnMerge(RpoDigest::default()),
+            format!(
+                "advice map key {} already exists with a different value when merging forests",
+                RpoDigest::default()
+            ),
+        ),
+        (
+            MastForestError::NodeCycle(node_id),
+            "node id: MastNodeId(5) is reachable from itself".to_string(),
+        ),
+    ];
+
+    for (err, expected) in errors {
+        assert_eq!(err.clone(), err);
+        assert_eq!(err.to_string(), expected);
+    }
+}
+
 proptest! {
     #[test]
     fn arbitrary_program_info_serialization_works(
@@ -46,6 +46,10 @@ impl AssemblyOp {
     }
 
     /// Returns the number of VM cycles taken to execute the assembly instruction of this decorator.
+    ///
+    /// Within a basic block, this is the number of operations the instruction was compiled to,
+    /// starting with the operation before which the decorator is placed (see
+    /// [crate::mast::BasicBlockNode::asm_op_ranges]).
     pub const fn num_cycles(&self) -> u8 {
         self.num_cycles
     }
@@ -36,6 +36,11 @@ pub enum Decorator {
     /// decorator is executed only in debug mode.
     Debug(DebugOptions),
     /// Emits a trace to the host.
+    ///
+    /// The tag carried by this decorator is passed to the host via `Host::on_trace()` when the
+    /// decorator is encountered, which makes it possible to correlate VM execution with external
+    /// traces. Like all decorators, it has no effect on the execution trace and is not enforced by
+    /// the STARK constraints.
     Trace(u32),
 }
 
@@ -110,6 +115,21 @@ impl<'a> DecoratorIterator<'a> {
             None
         }
     }
+
+    /// Returns the next decorator without advancing the iterator, or None if all decorators have
+    /// been consumed.
+    #[inline(always)]
+    pub fn peek(&self) -> Option<&'a DecoratorId> {
+        self.decorators.get(self.idx).map(|(_, decorator_id)| decorator_id)
+    }
+
+    /// Returns the position (i.e., the index of the operation before which it should be executed)
+    /// of the next decorator without advancing the iterator, or None if all decorators have been
+    /// consumed.
+    #[inline(always)]
+    pub fn peek_position(&self) -> Option<usize> {
+        self.decorators.get(self.idx).map(|&(pos, _)| pos)
+    }
 }
 
 impl<'a> Iterator for DecoratorIterator<'a> {
@@ -584,6 +584,111 @@ impl Operation {
         unsafe { *<*const _>::from(self).cast::<u8>() }
     }
 
+    /// Returns information about the operation with the specified opcode, or `None` if no
+    /// operation is assigned to the opcode.
+    ///
+    /// This is the inverse of [`Operation::op_code`], and can be used, for example, by a
+    /// disassembler to decode the mnemonic of an operation and to determine whether the opcode is
+    /// followed by associated data.
+    pub const fn from_op_code(op_code: u8) -> Option<OpCodeInfo> {
+        let info = match op_code {
+            OPCODE_NOOP => OpCodeInfo::new(OPCODE_NOOP, "noop"),
+            OPCODE_EQZ => OpCodeInfo::new(OPCODE_EQZ, "eqz"),
+            OPCODE_NEG => OpCodeInfo::new(OPCODE_NEG, "neg"),
+            OPCODE_INV => OpCodeInfo::new(OPCODE_INV, "inv"),
+            OPCODE_INCR => OpCodeInfo::new(OPCODE_INCR, "incr"),
+            OPCODE_NOT => OpCodeInfo::new(OPCODE_NOT, "not"),
+            OPCODE_FMPADD => OpCodeInfo::new(OPCODE_FMPADD, "fmpadd"),
+            OPCODE_MLOAD => OpCodeInfo::new(OPCODE_MLOAD, "mload"),
+            OPCODE_SWAP => OpCodeInfo::new(OPCODE_SWAP, "swap"),
+            OPCODE_CALLER => OpCodeInfo::new(OPCODE_CALLER, "caller"),
+            OPCODE_MOVUP2 => OpCodeInfo::new(OPCODE_MOVUP2, "movup2"),
+            OPCODE_MOVDN2 => OpCodeInfo::new(OPCODE_MOVDN2, "movdn2"),
+            OPCODE_MOVUP3 => OpCodeInfo::new(OPCODE_MOVUP3, "movup3"),
+            OPCODE_MOVDN3 => OpCodeInfo::new(OPCODE_MOVDN3, "movdn3"),
+            OPCODE_ADVPOPW => OpCodeInfo::new(OPCODE_ADVPOPW, "advpopw"),
+            OPCODE_EXPACC => OpCodeInfo::new(OPCODE_EXPACC, "expacc"),
+            OPCODE_MOVUP4 => OpCodeInfo::new(OPCODE_MOVUP4, "movup4"),
+            OPCODE_MOVDN4 => OpCodeInfo::new(OPCODE_MOVDN4, "movdn4"),
+            OPCODE_MOVUP5 => OpCodeInfo::new(OPCODE_MOVUP5, "movup5"),
+            OPCODE_MOVDN5 => OpCodeInfo::new(OPCODE_MOVDN5, "movdn5"),
+            OPCODE_MOVUP6 => OpCodeInfo::new(OPCODE_MOVUP6, "movup6"),
+            OPCODE_MOVDN6 => OpCodeInfo::new(OPCODE_MOVDN6, "movdn6"),
+            OPCODE_MOVUP7 => OpCodeInfo::new(OPCODE_MOVUP7, "movup7"),
+            OPCODE_MOVDN7 => OpCodeInfo::new(OPCODE_MOVDN7, "movdn7"),
+            OPCODE_SWAPW => OpCodeInfo::new(OPCODE_SWAPW, "swapw"),
+            OPCODE_EXT2MUL => OpCodeInfo::new(OPCODE_EXT2MUL, "ext2mul"),
+            OPCODE_MOVUP8 => OpCodeInfo::new(OPCODE_MOVUP8, "movup8"),
+            OPCODE_MOVDN8 => OpCodeInfo::new(OPCODE_MOVDN8, "movdn8"),
+            OPCODE_SWAPW2 => OpCodeInfo::new(OPCODE_SWAPW2, "swapw2"),
+            OPCODE_SWAPW3 => OpCodeInfo::new(OPCODE_SWAPW3, "swapw3"),
+            OPCODE_SWAPDW => OpCodeInfo::new(OPCODE_SWAPDW, "swapdw"),
+            OPCODE_ASSERT => OpCodeInfo::with_err_code(OPCODE_ASSERT, "assert"),
+            OPCODE_EQ => OpCodeInfo::new(OPCODE_EQ, "eq"),
+            OPCODE_ADD => OpCodeInfo::new(OPCODE_ADD, "add"),
+            OPCODE_MUL => OpCodeInfo::new(OPCODE_MUL, "mul"),
+            OPCODE_AND => OpCodeInfo::new(OPCODE_AND, "and"),
+            OPCODE_OR => OpCodeInfo::new(OPCODE_OR, "or"),
+            OPCODE_U32AND => OpCodeInfo::new(OPCODE_U32AND, "u32and"),
+            OPCODE_U32XOR => OpCodeInfo::new(OPCODE_U32XOR, "u32xor"),
+            OPCODE_FRIE2F4 => OpCodeInfo::new(OPCODE_FRIE2F4, "frie2f4"),
+            OPCODE_DROP => OpCodeInfo::new(OPCODE_DROP, "drop"),
+            OPCODE_CSWAP => OpCodeInfo::new(OPCODE_CSWAP, "cswap"),
+            OPCODE_CSWAPW => OpCodeInfo::new(OPCODE_CSWAPW, "cswapw"),
+            OPCODE_MLOADW => OpCodeInfo::new(OPCODE_MLOADW, "mloadw"),
+            OPCODE_MSTORE => OpCodeInfo::new(OPCODE_MSTORE, "mstore"),
+            OPCODE_MSTOREW => OpCodeInfo::new(OPCODE_MSTOREW, "mstorew"),
+            OPCODE_FMPUPDATE => OpCodeInfo::new(OPCODE_FMPUPDATE, "fmpupdate"),
+            OPCODE_PAD => OpCodeInfo::new(OPCODE_PAD, "pad"),
+            OPCODE_DUP0 => OpCodeInfo::new(OPCODE_DUP0, "dup0"),
+            OPCODE_DUP1 => OpCodeInfo::new(OPCODE_DUP1, "dup1"),
+            OPCODE_DUP2 => OpCodeInfo::new(OPCODE_DUP2, "dup2"),
+            OPCODE_DUP3 => OpCodeInfo::new(OPCODE_DUP3, "dup3"),
+            OPCODE_DUP4 => OpCodeInfo::new(OPCODE_DUP4, "dup4"),
+            OPCODE_DUP5 => OpCodeInfo::new(OPCODE_DUP5, "dup5"),
+            OPCODE_DUP6 => OpCodeInfo::new(OPCODE_DUP6, "dup6"),
+            OPCODE_DUP7 => OpCodeInfo::new(OPCODE_DUP7, "dup7"),
+            OPCODE_DUP9 => OpCodeInfo::new(OPCODE_DUP9, "dup9"),
+            OPCODE_DUP11 => OpCodeInfo::new(OPCODE_DUP11, "dup11"),
+            OPCODE_DUP13 => OpCodeInfo::new(OPCODE_DUP13, "dup13"),
+            OPCODE_DUP15 => OpCodeInfo::new(OPCODE_DUP15, "dup15"),
+            OPCODE_ADVPOP => OpCodeInfo::new(OPCODE_ADVPOP, "advpop"),
+            OPCODE_SDEPTH => OpCodeInfo::new(OPCODE_SDEPTH, "sdepth"),
+            OPCODE_CLK => OpCodeInfo::new(OPCODE_CLK, "clk"),
+            OPCODE_U32ADD => OpCodeInfo::new(OPCODE_U32ADD, "u32add"),
+            OPCODE_U32SUB => OpCodeInfo::new(OPCODE_U32SUB, "u32sub"),
+            OPCODE_U32MUL => OpCodeInfo::new(OPCODE_U32MUL, "u32mul"),
+            OPCODE_U32DIV => OpCodeInfo::new(OPCODE_U32DIV, "u32div"),
+            OPCODE_U32SPLIT => OpCodeInfo::new(OPCODE_U32SPLIT, "u32split"),
+            OPCODE_U32ASSERT2 => OpCodeInfo::with_err_code(OPCODE_U32ASSERT2, "u32assert2"),
+            OPCODE_U32ADD3 => OpCodeInfo::new(OPCODE_U32ADD3, "u32add3"),
+            OPCODE_U32MADD => OpCodeInfo::new(OPCODE_U32MADD, "u32madd"),
+            OPCODE_HPERM => OpCodeInfo::new(OPCODE_HPERM, "hperm"),
+            OPCODE_MPVERIFY => OpCodeInfo::with_err_code(OPCODE_MPVERIFY, "mpverify"),
+            OPCODE_PIPE => OpCodeInfo::new(OPCODE_PIPE, "pipe"),
+            OPCODE_MSTREAM => OpCodeInfo::new(OPCODE_MSTREAM, "mstream"),
+            OPCODE_SPLIT => OpCodeInfo::new(OPCODE_SPLIT, "split"),
+            OPCODE_LOOP => OpCodeInfo::new(OPCODE_LOOP, "loop"),
+            OPCODE_SPAN => OpCodeInfo::new(OPCODE_SPAN, "span"),
+            OPCODE_JOIN => OpCodeInfo::new(OPCODE_JOIN, "join"),
+            OPCODE_DYN => OpCodeInfo::new(OPCODE_DYN, "dyn"),
+            OPCODE_RCOMBBASE => OpCodeInfo::new(OPCODE_RCOMBBASE, "rcomb1"),
+            OPCODE_EMIT => OpCodeInfo::with_imm_value(OPCODE_EMIT, "emit"),
+            OPCODE_PUSH => OpCodeInfo::with_imm_value(OPCODE_PUSH, "push"),
+            OPCODE_DYNCALL => OpCodeInfo::new(OPCODE_DYNCALL, "dyncall"),
+            OPCODE_MRUPDATE => OpCodeInfo::new(OPCODE_MRUPDATE, "mrupdate"),
+            OPCODE_SYSCALL => OpCodeInfo::new(OPCODE_SYSCALL, "syscall"),
+            OPCODE_CALL => OpCodeInfo::new(OPCODE_CALL, "call"),
+            OPCODE_END => OpCodeInfo::new(OPCODE_END, "end"),
+            OPCODE_REPEAT => OpCodeInfo::new(OPCODE_REPEAT, "repeat"),
+            OPCODE_RESPAN => OpCodeInfo::new(OPCODE_RESPAN, "respan"),
+            OPCODE_HALT => OpCodeInfo::new(OPCODE_HALT, "halt"),
+            _ => return None,
+        };
+
+        Some(info)
+    }
+
     /// Returns an immediate value carried by this operation.
     pub fn imm_value(&self) -> Option<Felt> {
         match *self {
@@ -611,6 +716,75 @@ impl Operation {
                 | Self::SysCall
         )
     }
+
+    /// Returns true if this is a flow control operation, i.e., an operation which is executed by
+    /// the decoder to mark the boundaries of program blocks rather than by the stack.
+    pub fn is_control_flow(&self) -> bool {
+        matches!(
+            self,
+            Self::Join
+                | Self::Split
+                | Self::Loop
+                | Self::Call
+                | Self::Dyn
+                | Self::Dyncall
+                | Self::SysCall
+                | Self::Span
+                | Self::End
+                | Self::Repeat
+                | Self::Respan
+                | Self::Halt
+        )
+    }
+
+    /// Returns true if this operation shifts the operand stack to the left.
+    ///
+    /// This describes the effect of the operation on the stack, which for `DYNCALL` differs from
+    /// the aggregate `left_shift` flag of the stack constraints: `DYNCALL` drops the address of the
+    /// callee hash from the stack when the new execution context is started, and so `true` is
+    /// returned for it, even though it is excluded from that flag.
+    ///
+    /// `END` shifts the stack to the left only when it ends a loop which was entered. Since this
+    /// depends on the execution context, `false` is returned for it.
+    pub fn shifts_stack_left(&self) -> bool {
+        // operations with opcodes 010xxxx shift the stack to the left
+        self.op_code() >> 4 == 0b010
+            || matches!(
+                self,
+                Self::U32add3
+                    | Self::U32madd
+                    | Self::Split
+                    | Self::Loop
+                    | Self::Repeat
+                    | Self::Dyn
+                    | Self::Dyncall
+            )
+    }
+
+    /// Returns true if this operation shifts the operand stack to the right.
+    pub fn shifts_stack_right(&self) -> bool {
+        // operations with opcodes 011xxxx shift the stack to the right
+        self.op_code() >> 4 == 0b011 || matches!(self, Self::U32split | Self::Push(_))
+    }
+
+    /// Returns the number of decoder user op helper registers this operation populates.
+    ///
+    /// Operations which populate the decoder hasher registers (see
+    /// [Self::populates_decoder_hasher_registers]) have no user op helper registers available, and
+    /// thus 0 is returned for them.
+    pub fn num_helper_registers(&self) -> usize {
+        match self {
+            Self::Eq | Self::Eqz | Self::Expacc | Self::Emit(_) => 1,
+            Self::HPerm | Self::MpVerify(_) | Self::MrUpdate => 1,
+            Self::MLoad | Self::MStore => 3,
+            // range checks of the 16-bit limbs of the result
+            Self::U32add | Self::U32add3 | Self::U32sub | Self::U32div | Self::U32assert2(_) => 4,
+            // range checks plus the value used to enforce the element validity constraint
+            Self::U32split | Self::U32mul | Self::U32madd => 5,
+            Self::FriE2F4 | Self::RCombBase => 6,
+            _ => 0,
+        }
+    }
 }
 
 impl crate::prettier::PrettyPrint for Operation {
@@ -992,3 +1166,239 @@ impl Deserializable for Operation {
         Ok(operation)
     }
 }
+
+// OPCODE INFO
+// ================================================================================================
+
+/// Information about an operation which can be derived from its opcode alone.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OpCodeInfo {
+    op_code: u8,
+    mnemonic: &'static str,
+    has_imm_value: bool,
+    has_err_code: bool,
+}
+
+impl OpCodeInfo {
+    const fn new(op_code: u8, mnemonic: &'static str) -> Self {
+        Self {
+            op_code,
+            mnemonic,
+            has_imm_value: false,
+            has_err_code: false,
+        }
+    }
+
+    const fn with_imm_value(op_code: u8, mnemonic: &'static str) -> Self {
+        Self {
+            has_imm_value: true,
+            ..Self::new(op_code, mnemonic)
+        }
+    }
+
+    const fn with_err_code(op_code: u8, mnemonic: &'static str) -> Self {
+        Self {
+            has_err_code: true,
+            ..Self::new(op_code, mnemonic)
+        }
+    }
+
+    /// Returns the opcode of the operation.
+    pub const fn op_code(&self) -> u8 {
+        self.op_code
+    }
+
+    /// Returns the mnemonic of the operation, as used when the operation is displayed.
+    pub const fn mnemonic(&self) -> &'static str {
+        self.mnemonic
+    }
+
+    /// Returns true if the operation carries an immediate value (see [`Operation::imm_value`]).
+    pub const fn has_imm_value(&self) -> bool {
+        self.has_imm_value
+    }
+
+    /// Returns true if the operation carries an error code which is reported if the operation
+    /// fails.
+    pub const fn has_err_code(&self) -> bool {
+        self.has_err_code
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::ONE;
+
+    #[test]
+    fn op_code_info_round_trip() {
+        let operations = [
+            Operation::Noop,
+            Operation::Assert(1),
+            Operation::FmpAdd,
+            Operation::FmpUpdate,
+            Operation::SDepth,
+            Operation::Caller,
+            Operation::Clk,
+            Operation::Join,
+            Operation::Split,
+            Operation::Loop,
+            Operation::Call,
+            Operation::Dyn,
+            Operation::Dyncall,
+            Operation::SysCall,
+            Operation::Span,
+            Operation::End,
+            Operation::Repeat,
+            Operation::Respan,
+            Operation::Halt,
+            Operation::Add,
+            Operation::Neg,
+            Operation::Mul,
+            Operation::Inv,
+            Operation::Incr,
+            Operation::And,
+            Operation::Or,
+            Operation::Not,
+            Operation::Eq,
+            Operation::Eqz,
+            Operation::Expacc,
+            Operation::Ext2Mul,
+            Operation::U32split,
+            Operation::U32add,
+            Operation::U32assert2(2),
+            Operation::U32add3,
+            Operation::U32sub,
+            Operation::U32mul,
+            Operation::U32madd,
+            Operation::U32div,
+            Operation::U32and,
+            Operation::U32xor,
+            Operation::Pad,
+            Operation::Drop,
+            Operation::Dup0,
+            Operation::Dup1,
+            Operation::Dup2,
+            Operation::Dup3,
+            Operation::Dup4,
+            Operation::Dup5,
+            Operation::Dup6,
+            Operation::Dup7,
+            Operation::Dup9,
+            Operation::Dup11,
+            Operation::Dup13,
+            Operation::Dup15,
+            Operation::Swap,
+            Operation::SwapW,
+            Operation::SwapW2,
+            Operation::SwapW3,
+            Operation::SwapDW,
+            Operation::MovUp2,
+            Operation::MovUp3,
+            Operation::MovUp4,
+            Operation::MovUp5,
+            Operation::MovUp6,
+            Operation::MovUp7,
+            Operation::MovUp8,
+            Operation::MovDn2,
+            Operation::MovDn3,
+            Operation::MovDn4,
+            Operation::MovDn5,
+            Operation::MovDn6,
+            Operation::MovDn7,
+            Operation::MovDn8,
+            Operation::CSwap,
+            Operation::CSwapW,
+            Operation::Push(ONE),
+            Operation::AdvPop,
+            Operation::AdvPopW,
+            Operation::MLoadW,
+            Operation::MStoreW,
+            Operation::MLoad,
+            Operation::MStore,
+            Operation::MStream,
+            Operation::Pipe,
+            Operation::Emit(3),
+            Operation::HPerm,
+            Operation::MpVerify(4),
+            Operation::MrUpdate,
+            Operation::FriE2F4,
+            Operation::RCombBase,
+        ];
+
+        for op in operations {
+            let info = Operation::from_op_code(op.op_code()).expect("opcode should be defined");
+            assert_eq!(info.op_code(), op.op_code());
+            assert_eq!(info.has_imm_value(), op.imm_value().is_some(), "{op}");
+            assert_eq!(
+                info.has_err_code(),
+                matches!(
+                    op,
+                    Operation::Assert(_) | Operation::U32assert2(_) | Operation::MpVerify(_)
+                ),
+                "{op}"
+            );
+
+            // operations with associated data are displayed as `mnemonic(data)`
+            let displayed = op.to_string();
+            let displayed = displayed.trim_end();
+            if info.has_imm_value() || info.has_err_code() {
+                assert!(displayed.starts_with(&format!("{}(", info.mnemonic())), "{displayed}");
+            } else {
+                assert_eq!(displayed, info.mnemonic());
+            }
+        }
+
+        // every defined opcode belongs to exactly one of the operations above
+        let num_defined = (0..=u8::MAX).filter_map(Operation::from_op_code).count();
+        assert_eq!(num_defined, operations.len());
+        assert_eq!(Operation::from_op_code(0b0110_0100), None);
+    }
+
+    #[test]
+    fn op_grouping_metadata() {
+        // flow control operations
+        assert!(Operation::Join.is_control_flow());
+        assert!(Operation::Respan.is_control_flow());
+        assert!(!Operation::Add.is_control_flow());
+        assert_eq!(Operation::Span.num_helper_registers(), 0);
+
+        // operations which shift the stack to the left
+        for op in [
+            Operation::Add,
+            Operation::Drop,
+            Operation::U32madd,
+            Operation::Split,
+            Operation::Dyn,
+            Operation::Dyncall,
+        ] {
+            assert!(op.shifts_stack_left(), "{op}");
+            assert!(!op.shifts_stack_right(), "{op}");
+        }
+
+        // operations which shift the stack to the right
+        for op in [Operation::Pad, Operation::Dup3, Operation::Push(ONE), Operation::U32split] {
+            assert!(op.shifts_stack_right(), "{op}");
+            assert!(!op.shifts_stack_left(), "{op}");
+        }
+
+        // operations which do not shift the stack
+        for op in [Operation::Swap, Operation::Neg, Operation::HPerm, Operation::End] {
+            assert!(!op.shifts_stack_left(), "{op}");
+            assert!(!op.shifts_stack_right(), "{op}");
+        }
+
+        // helper registers
+        assert_eq!(Operation::Swap.num_helper_registers(), 0);
+        assert_eq!(Operation::Eq.num_helper_registers(), 1);
+        assert_eq!(Operation::MLoad.num_helper_registers(), 3);
+        assert_eq!(Operation::U32add.num_helper_registers(), 4);
+        assert_eq!(Operation::U32mul.num_helper_registers(), 5);
+        assert_eq!(Operation::FriE2F4.num_helper_registers(), 6);
+    }
+}
@@ -1,4 +1,10 @@
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use core::fmt;
 
 use miden_crypto::{hash::rpo::RpoDigest, Felt, WORD_SIZE};
@@ -6,8 +12,10 @@ use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError,
 
 use super::Kernel;
 use crate::{
-    mast::{MastForest, MastNode, MastNodeId},
+    errors::ProgramError,
+    mast::{BasicBlockNode, MastForest, MastNode, MastNodeId, OperationOrDecorator},
     utils::ToElements,
+    AdviceInjector, Decorator, Operation, ONE, ZERO,
 };
 
 // PROGRAM
@@ -48,10 +56,50 @@ impl Program {
         entrypoint: MastNodeId,
         kernel: Kernel,
     ) -> Self {
-        assert!(mast_forest.get_node_by_id(entrypoint).is_some(), "invalid entrypoint");
-        assert!(mast_forest.is_procedure_root(entrypoint), "entrypoint not a procedure");
+        match Self::try_with_kernel(mast_forest, entrypoint, kernel) {
+            Ok(program) => program,
+            Err(err) => panic!("{err}"),
+        }
+    }
 
-        Self { mast_forest, entrypoint, kernel }
+    /// Construct a new [`Program`] from the given MAST forest and entrypoint. The kernel is assumed
+    /// to be empty.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `mast_forest` doesn't contain the specified entrypoint (e.g., because the entrypoint was
+    ///   taken from a different MAST forest).
+    /// - the specified entrypoint is not a procedure root in the `mast_forest`.
+    pub fn try_new(
+        mast_forest: Arc<MastForest>,
+        entrypoint: MastNodeId,
+    ) -> Result<Self, ProgramError> {
+        Self::try_with_kernel(mast_forest, entrypoint, Kernel::default())
+    }
+
+    /// Construct a new [`Program`] from the given MAST forest, entrypoint, and kernel.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `mast_forest` doesn't contain the specified entrypoint (e.g., because the entrypoint was
+    ///   taken from a different MAST forest).
+    /// - the specified entrypoint is not a procedure root in the `mast_forest`.
+    pub fn try_with_kernel(
+        mast_forest: Arc<MastForest>,
+        entrypoint: MastNodeId,
+        kernel: Kernel,
+    ) -> Result<Self, ProgramError> {
+        if mast_forest.get_node_by_id(entrypoint).is_none() {
+            return Err(ProgramError::EntrypointNotInForest(
+                entrypoint,
+                mast_forest.num_nodes() as usize,
+            ));
+        }
+        if !mast_forest.is_procedure_root(entrypoint) {
+            return Err(ProgramError::EntrypointNotProcedureRoot(entrypoint));
+        }
+
+        Ok(Self { mast_forest, entrypoint, kernel })
     }
 }
 
@@ -101,6 +149,43 @@ impl Program {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+/// Static analysis
+impl Program {
+    /// Returns the advice map keys which are known to be read by this program.
+    ///
+    /// The MAST reachable from the program's entrypoint is scanned for advice injectors which copy
+    /// values from the advice map (i.e., `adv.push_mapval` and `adv.push_mapvaln`). A key is
+    /// detected only if it is pushed onto the stack via constants in the same basic block
+    /// right before the injector is executed (e.g., `push.KEY adv.push_mapval`). This can be
+    /// used to validate that the advice map contains all the required entries before the program
+    /// is executed.
+    ///
+    /// Keys which are computed at runtime (or passed in via the stack, memory, or advice provider)
+    /// cannot be detected, nor can keys read by procedures which are not part of the program's
+    /// MAST forest (i.e., which are referenced via external nodes). Thus, the returned set is a
+    /// subset of the keys that the program may read.
+    pub fn required_advice_keys(&self) -> BTreeSet<RpoDigest> {
+        let mut keys = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        let mut to_visit = vec![self.entrypoint];
+
+        while let Some(node_id) = to_visit.pop() {
+            if !visited.insert(node_id) {
+                continue;
+            }
+
+            let node = &self.mast_forest[node_id];
+            if let MastNode::Block(basic_block) = node {
+                collect_advice_keys(&self.mast_forest, basic_block, &mut keys);
+            }
+            to_visit.extend(node.children());
+        }
+
+        keys
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 /// Serialization
 #[cfg(feature = "std")]
@@ -173,6 +258,23 @@ impl crate::prettier::PrettyPrint for Program {
     }
 }
 
+impl Program {
+    /// Renders the MAST of this program's entrypoint as MASM-like source code.
+    ///
+    /// The output is a `begin`/`end` block containing the rendering of each node of the tree
+    /// rooted at the entrypoint. It is meant for inspecting compiled programs, and is only roughly
+    /// re-assemblable for simple programs, since:
+    /// - basic blocks are rendered as `basic_block <ops> end`, where `<ops>` are VM operations
+    ///   rather than assembly instructions (e.g., `pad incr` instead of `push.1`);
+    /// - procedures invoked via `exec` are inlined and `repeat` blocks are unrolled, as neither is
+    ///   represented in the MAST;
+    /// - external nodes render as `external.<digest>`, where `<digest>` is the MAST root of the
+    ///   referenced procedure.
+    pub fn to_masm_string(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use crate::prettier::PrettyPrint;
@@ -180,6 +282,53 @@ impl fmt::Display for Program {
     }
 }
 
+// HELPERS
+// ===============================================================================================
+
+/// Adds the advice map keys read by the basic block to `keys`, for all keys which can be
+/// determined statically.
+///
+/// This tracks the values at the top of the stack which are known from constants pushed within the
+/// basic block; any operation other than the ones used by the assembler to push constants
+/// invalidates the tracked values.
+fn collect_advice_keys(
+    mast_forest: &MastForest,
+    basic_block: &BasicBlockNode,
+    keys: &mut BTreeSet<RpoDigest>,
+) {
+    // the known values at the top of the stack, with the top of the stack being the last element
+    let mut known_stack: Vec<Felt> = Vec::new();
+
+    for item in basic_block.iter() {
+        match item {
+            OperationOrDecorator::Operation(op) => match op {
+                Operation::Noop => (),
+                Operation::Pad => known_stack.push(ZERO),
+                Operation::Push(value) => known_stack.push(*value),
+                Operation::Incr => match known_stack.last_mut() {
+                    Some(value) => *value += ONE,
+                    None => known_stack.clear(),
+                },
+                _ => known_stack.clear(),
+            },
+            OperationOrDecorator::Decorator(&decorator_id) => {
+                if let Decorator::Advice(AdviceInjector::MapValueToStack { key_offset, .. }) =
+                    mast_forest[decorator_id]
+                {
+                    // the key is read as [s3, s2, s1, s0] relative to `key_offset`
+                    let end = known_stack.len().checked_sub(key_offset);
+                    if let Some(start) = end.and_then(|end| end.checked_sub(WORD_SIZE)) {
+                        let key: [Felt; WORD_SIZE] = known_stack[start..start + WORD_SIZE]
+                            .try_into()
+                            .expect("slice has word size");
+                        keys.insert(key.into());
+                    }
+                }
+            },
+        }
+    }
+}
+
 // PROGRAM INFO
 // ===============================================================================================
 
@@ -228,6 +377,17 @@ impl From<Program> for ProgramInfo {
     }
 }
 
+impl fmt::Display for ProgramInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "program hash: {}, kernel procedures: {}",
+            self.program_hash.to_hex(),
+            self.kernel.proc_hashes().len()
+        )
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Serialization
 
@@ -264,3 +424,165 @@ impl ToElements for ProgramInfo {
         result
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entrypoint_validation() {
+        // the entrypoint of a larger forest is out of range for a smaller forest
+        let mut large_forest = MastForest::new();
+        large_forest.add_block(vec![Operation::Add], None).unwrap();
+        let large_entrypoint = large_forest.add_block(vec![Operation::Mul], None).unwrap();
+        large_forest.make_root(large_entrypoint);
+
+        let mut small_forest = MastForest::new();
+        let small_entrypoint = small_forest.add_block(vec![Operation::Add], None).unwrap();
+        let small_forest = Arc::new(small_forest);
+
+        assert_eq!(
+            Program::try_new(small_forest.clone(), large_entrypoint),
+            Err(ProgramError::EntrypointNotInForest(large_entrypoint, 1))
+        );
+
+        // the entrypoint must be a procedure root
+        assert_eq!(
+            Program::try_new(small_forest.clone(), small_entrypoint),
+            Err(ProgramError::EntrypointNotProcedureRoot(small_entrypoint))
+        );
+
+        let program = Program::try_new(large_forest.into(), large_entrypoint).unwrap();
+        assert_eq!(program.entrypoint(), large_entrypoint);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a node of the MAST forest")]
+    fn new_panics_on_entrypoint_not_in_forest() {
+        let mut large_forest = MastForest::new();
+        large_forest.add_block(vec![Operation::Add], None).unwrap();
+        let large_entrypoint = large_forest.add_block(vec![Operation::Mul], None).unwrap();
+
+        Program::new(MastForest::new().into(), large_entrypoint);
+    }
+
+    #[test]
+    fn required_advice_keys() {
+        let mut mast_forest = MastForest::new();
+        let map_value_to_stack = |key_offset| {
+            Decorator::Advice(AdviceInjector::MapValueToStack { include_len: false, key_offset })
+        };
+        let push_mapval = mast_forest.add_decorator(map_value_to_stack(0)).unwrap();
+        let push_mapval_offset = mast_forest.add_decorator(map_value_to_stack(4)).unwrap();
+
+        // push.1.0.7.8 adv.push_mapval
+        let static_key_block = mast_forest
+            .add_block(
+                vec![
+                    Operation::Pad,
+                    Operation::Incr,
+                    Operation::Pad,
+                    Operation::Push(Felt::new(7)),
+                    Operation::Push(Felt::new(8)),
+                    Operation::Drop,
+                ],
+                Some(vec![(5, push_mapval)]),
+            )
+            .unwrap();
+
+        // push.2.3.4.5 push.6 push.7 push.8 push.9 adv.push_mapval.4
+        let offset_key_block = mast_forest
+            .add_block(
+                (2..10).map(|value| Operation::Push(Felt::new(value))).collect(),
+                Some(vec![(8, push_mapval_offset)]),
+            )
+            .unwrap();
+
+        // push.1.2.3 add adv.push_mapval (the key is computed at runtime)
+        let dynamic_key_block = mast_forest
+            .add_block(
+                vec![
+                    Operation::Push(Felt::new(1)),
+                    Operation::Push(Felt::new(2)),
+                    Operation::Push(Felt::new(3)),
+                    Operation::Add,
+                    Operation::Drop,
+                ],
+                Some(vec![(4, push_mapval)]),
+            )
+            .unwrap();
+
+        let split = mast_forest.add_split(offset_key_block, dynamic_key_block).unwrap();
+        let entrypoint = mast_forest.add_join(static_key_block, split).unwrap();
+        mast_forest.make_root(entrypoint);
+
+        // a procedure which is not reachable from the entrypoint is not scanned
+        let unreachable_block = mast_forest
+            .add_block(
+                vec![Operation::Push(Felt::new(10)); 4]
+                    .into_iter()
+                    .chain([Operation::Drop])
+                    .collect(),
+                Some(vec![(4, push_mapval)]),
+            )
+            .unwrap();
+        mast_forest.make_root(unreachable_block);
+
+        let program = Program::new(mast_forest.into(), entrypoint);
+
+        let expected: BTreeSet<RpoDigest> = [
+            [ONE, ZERO, Felt::new(7), Felt::new(8)].into(),
+            [Felt::new(2), Felt::new(3), Felt::new(4), Felt::new(5)].into(),
+        ]
+        .into();
+        assert_eq!(program.required_advice_keys(), expected);
+    }
+
+    #[test]
+    fn program_info_to_elements() {
+        let program_hash = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let proc_hashes = [
+            RpoDigest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+            RpoDigest::new([Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)]),
+        ];
+        let kernel = Kernel::new(&proc_hashes).unwrap();
+        let program_info = ProgramInfo::new(program_hash, kernel.clone());
+
+        // the program hash is followed by the hashes of the kernel procedures
+        let elements = program_info.to_elements();
+        assert_eq!(elements.len(), WORD_SIZE + proc_hashes.len() * WORD_SIZE);
+        assert_eq!(&elements[..WORD_SIZE], program_hash.as_elements());
+        let kernel_elements: Vec<Felt> = kernel
+            .proc_hashes()
+            .iter()
+            .flat_map(|hash| hash.as_elements().to_vec())
+            .collect();
+        assert_eq!(&elements[WORD_SIZE..], kernel_elements.as_slice());
+
+        // without a kernel, only the program hash is included
+        let program_info = ProgramInfo::new(program_hash, Kernel::default());
+        assert_eq!(program_info.to_elements(), program_hash.as_elements());
+    }
+
+    #[test]
+    fn program_info_accessors_and_display() {
+        let program_hash = RpoDigest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let proc_hashes = [
+            RpoDigest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+            RpoDigest::new([Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)]),
+        ];
+        let kernel = Kernel::new(&proc_hashes).unwrap();
+        let program_info = ProgramInfo::new(program_hash, kernel.clone());
+
+        assert_eq!(program_info.program_hash(), &program_hash);
+        assert_eq!(program_info.kernel(), &kernel);
+        assert_eq!(program_info.kernel_procedures(), kernel.proc_hashes());
+        assert_eq!(
+            program_info.to_string(),
+            format!("program hash: {}, kernel procedures: 2", program_hash.to_hex())
+        );
+    }
+}
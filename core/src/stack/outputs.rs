@@ -86,6 +86,15 @@ impl StackOutputs {
         Some(word_elements)
     }
 
+    /// Returns the word at the top of the stack.
+    ///
+    /// This is the word a program leaves on the stack when its result is a digest. As with
+    /// [Self::get_stack_word], the elements are reversed relative to the stack: if the outputs are
+    /// `[a, b, c, d, ...]` with `a` at the top of the stack, the returned word is `[d, c, b, a]`.
+    pub fn top_word(&self) -> Word {
+        self.get_stack_word(0).expect("stack outputs contain at least 4 elements")
+    }
+
     /// Returns the number of requested stack outputs or returns the full stack if fewer than the
     /// requested number of stack values exist.
     pub fn stack_truncated(&self, num_outputs: usize) -> &[Felt] {
@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+use miden_crypto::{Felt, Word};
+
 use crate::{
     utils::{Deserializable, Serializable},
     StackInputs, StackOutputs,
@@ -128,3 +130,15 @@ fn test_outputs_empty() {
 
     assert_eq!(*inputs, *result);
 }
+
+// OUTPUT ACCESSOR TESTS
+// ================================================================================================
+
+#[test]
+fn test_outputs_top_word() {
+    let outputs = StackOutputs::try_from_ints([1, 2, 3, 4, 5, 6]).unwrap();
+
+    let expected: Word = [Felt::new(4), Felt::new(3), Felt::new(2), Felt::new(1)];
+    assert_eq!(outputs.top_word(), expected);
+    assert_eq!(outputs.get_stack_word(0), Some(expected));
+}
@@ -16,7 +16,7 @@ pub use winter_utils::group_slice_elements;
 #[cfg(feature = "std")]
 pub use winter_utils::ReadAdapter;
 
-use crate::Felt;
+use crate::{Felt, StarkField};
 
 pub mod math {
     pub use math::batch_inversion;
@@ -41,6 +41,28 @@ impl ToElements for Vec<u64> {
     }
 }
 
+// FIELD ELEMENTS
+// ================================================================================================
+
+/// Converts the provided values into field elements, making sure that all of them are canonical
+/// (i.e., smaller than the field modulus).
+///
+/// # Errors
+/// Returns the index of the first value which is not a canonical field element.
+pub fn try_into_felts(values: &[u64]) -> Result<Vec<Felt>, usize> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, &value)| Felt::try_from(value).map_err(|_| idx))
+        .collect()
+}
+
+/// Returns true if all of the provided values are canonical field elements (i.e., smaller than
+/// the field modulus).
+pub fn all_canonical(values: &[u64]) -> bool {
+    values.iter().all(|&value| value < Felt::MODULUS)
+}
+
 // INTO BYTES
 // ================================================================================================
 
@@ -126,6 +148,24 @@ fn debug_assert_is_checked() {
     debug_assert!(false);
 }
 
+#[test]
+fn felt_canonical_validation() {
+    let max = Felt::MODULUS - 1;
+
+    assert_eq!(try_into_felts(&[]), Ok(Vec::new()));
+    assert_eq!(
+        try_into_felts(&[0, 1, max]),
+        Ok(vec![Felt::new(0), Felt::new(1), Felt::new(max)])
+    );
+    assert!(all_canonical(&[0, 1, max]));
+
+    // the modulus itself is the first non-canonical value
+    assert_eq!(try_into_felts(&[0, Felt::MODULUS, u64::MAX]), Err(1));
+    assert_eq!(try_into_felts(&[u64::MAX]), Err(0));
+    assert!(!all_canonical(&[0, 1, Felt::MODULUS]));
+    assert!(all_canonical(&[]));
+}
+
 // FORMATTING
 // ================================================================================================
 
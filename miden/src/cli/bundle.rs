@@ -1,10 +1,16 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, fs, path::PathBuf};
 
 use assembly::{
     diagnostics::{IntoDiagnostic, Report},
-    Assembler, Library, LibraryNamespace,
+    Assembler, Library, LibraryNamespace, SourceManager, WriteOptions,
 };
 use clap::Parser;
+use serde_derive::Serialize;
+use vm_core::{
+    debuginfo::Location,
+    mast::{MastForest, MastNode, MastNodeId},
+    Decorator,
+};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(
@@ -21,6 +27,17 @@ pub struct BundleCmd {
     /// Version of the library, defaults to `0.1.0`.
     #[clap(short, long, default_value = "0.1.0")]
     version: String,
+    /// Path to a JSON file to which a map from the MAST root of each exported procedure to its
+    /// location in the source code is written. Exports sharing a MAST root are listed together.
+    #[clap(long = "source-map", value_parser)]
+    source_map: Option<PathBuf>,
+    /// Path to the output `.masl` file, defaults to `<namespace>.masl` (or `out.masl`) inside
+    /// the library directory.
+    #[clap(short, long, value_parser)]
+    output: Option<PathBuf>,
+    /// Fail instead of overwriting the output file if it already exists.
+    #[clap(long = "no-overwrite")]
+    no_overwrite: bool,
 }
 
 impl BundleCmd {
@@ -40,19 +57,114 @@ impl BundleCmd {
         };
 
         let assembler = Assembler::default().with_debug_mode(true);
+        let source_manager = assembler.source_manager();
         let library_namespace =
             namespace.parse::<LibraryNamespace>().expect("invalid base namespace");
         let library = Library::from_dir(&self.dir, library_namespace, assembler)?;
 
+        // write the source map, if requested
+        if let Some(source_map_path) = &self.source_map {
+            let source_map = build_source_map(&library, source_manager.as_ref());
+            let source_map = serde_json::to_string_pretty(&source_map).into_diagnostic()?;
+            fs::write(source_map_path, source_map).into_diagnostic()?;
+        }
+
         // write the masl output
-        let output_file = self
-            .dir
-            .join(self.namespace.as_deref().unwrap_or("out"))
-            .with_extension(Library::LIBRARY_EXTENSION);
-        library.write_to_file(output_file).into_diagnostic()?;
+        let output_file = match &self.output {
+            Some(output) => output.clone(),
+            None => self
+                .dir
+                .join(self.namespace.as_deref().unwrap_or("out"))
+                .with_extension(Library::LIBRARY_EXTENSION),
+        };
+        let write_options = WriteOptions {
+            overwrite: !self.no_overwrite,
+            create_dirs: true,
+        };
+        library
+            .write_to_file_with_options(output_file, write_options)
+            .into_diagnostic()?;
 
         println!("Built library {}", namespace);
 
         Ok(())
     }
 }
+
+// SOURCE MAP
+// ================================================================================================
+
+/// The location in the source code from which a procedure was compiled.
+#[derive(Serialize, Debug)]
+struct SourceMapEntry {
+    procedure: String,
+    file: String,
+    line: u32,
+}
+
+/// Builds a map from the hex-encoded MAST root of each exported procedure of the library to the
+/// source location of its first instruction, as recorded by `AsmOp` decorators.
+///
+/// Since several exports can share the same MAST root (e.g., procedures with identical bodies),
+/// each MAST root maps to the entries of all such exports, in the order of their fully-qualified
+/// names. Procedures for which no source location is available (e.g., re-exported procedures) are
+/// omitted from the map.
+fn build_source_map(
+    library: &Library,
+    source_manager: &dyn SourceManager,
+) -> BTreeMap<String, Vec<SourceMapEntry>> {
+    let mast_forest = library.mast_forest();
+
+    let mut source_map = BTreeMap::new();
+    for proc_name in library.exports() {
+        let node_id = library.get_export_node_id(proc_name);
+        let Some(location) = find_first_location(mast_forest, node_id) else {
+            continue;
+        };
+        let Some(file_line_col) = source_manager
+            .location_to_span(location.clone())
+            .and_then(|span| source_manager.file_line_col(span).ok())
+        else {
+            continue;
+        };
+
+        source_map
+            .entry(mast_forest[node_id].digest().to_hex())
+            .or_insert_with(Vec::new)
+            .push(SourceMapEntry {
+                procedure: proc_name.to_string(),
+                file: file_line_col.path.to_string(),
+                line: file_line_col.line,
+            });
+    }
+
+    source_map
+}
+
+/// Returns the location of the first `AsmOp` decorator reachable from the specified node, without
+/// descending into the procedures invoked by `call` and `syscall` nodes.
+fn find_first_location(mast_forest: &MastForest, node_id: MastNodeId) -> Option<&Location> {
+    let node = &mast_forest[node_id];
+    let asm_op_location = |decorator_id| match &mast_forest[decorator_id] {
+        Decorator::AsmOp(assembly_op) => assembly_op.location(),
+        _ => None,
+    };
+
+    if let Some(location) = node.before_enter().iter().copied().find_map(asm_op_location) {
+        return Some(location);
+    }
+
+    match node {
+        MastNode::Block(block) => block
+            .decorators()
+            .iter()
+            .map(|&(_, decorator_id)| decorator_id)
+            .find_map(asm_op_location),
+        MastNode::Join(join) => find_first_location(mast_forest, join.first())
+            .or_else(|| find_first_location(mast_forest, join.second())),
+        MastNode::Split(split) => find_first_location(mast_forest, split.on_true())
+            .or_else(|| find_first_location(mast_forest, split.on_false())),
+        MastNode::Loop(loop_node) => find_first_location(mast_forest, loop_node.body()),
+        MastNode::Call(_) | MastNode::Dyn(_) | MastNode::External(_) => None,
+    }
+}
@@ -14,9 +14,11 @@ use assembly::{
 use miden_vm::{
     crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
     math::Felt,
-    utils::{Deserializable, SliceReader},
-    AdviceInputs, Digest, ExecutionProof, MemAdviceProvider, Program, StackInputs, StackOutputs,
-    Word,
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+    AdviceInputs, Digest, ExecutionProof, MemAdviceProvider, Program, ProgramInfo, StackInputs,
+    StackOutputs, Word,
 };
 use serde_derive::{Deserialize, Serialize};
 use stdlib::StdLibrary;
@@ -494,6 +496,76 @@ impl ProofFile {
     }
 }
 
+// PROOF BUNDLE FILE
+// ================================================================================================
+
+/// A self-contained bundle of everything needed to verify an execution proof: the program info,
+/// the stack inputs and outputs, and the proof itself.
+#[derive(Debug)]
+pub struct ProofBundle {
+    pub program_info: ProgramInfo,
+    pub stack_inputs: StackInputs,
+    pub stack_outputs: StackOutputs,
+    pub proof: ExecutionProof,
+}
+
+/// Helper methods to interact with proof bundle file
+impl ProofBundle {
+    /// Read proof bundle from file
+    #[instrument(name = "read_proof_bundle_file", fields(path = %path.display()), skip_all)]
+    pub fn read(path: &Path) -> Result<Self, String> {
+        // read the file to bytes
+        let file = fs::read(path).map_err(|err| {
+            format!("Failed to open proof bundle file `{}` - {}", path.display(), err)
+        })?;
+
+        // deserialize bytes into a proof bundle
+        Self::read_from_bytes(&file)
+            .map_err(|err| format!("Failed to decode proof bundle data - {}", err))
+    }
+
+    /// Write proof bundle to file
+    #[instrument(name = "write_data_to_proof_bundle_file",
+                 fields(path = %path.display(), size = format!("{} KB", self.to_bytes().len() / 1024)),
+                 skip_all)]
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        // create output file
+        let mut file = fs::File::create(path).map_err(|err| {
+            format!("Failed to create proof bundle file `{}` - {}", path.display(), err)
+        })?;
+
+        // write bundle bytes to file
+        file.write_all(&self.to_bytes()).map_err(|err| {
+            format!("Failed to write proof bundle file `{}` - {}", path.display(), err)
+        })
+    }
+}
+
+impl Serializable for ProofBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program_info.write_into(target);
+        self.stack_inputs.write_into(target);
+        self.stack_outputs.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for ProofBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program_info = source.read()?;
+        let stack_inputs = source.read()?;
+        let stack_outputs = source.read()?;
+        let proof = source.read()?;
+
+        Ok(Self {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+        })
+    }
+}
+
 // PROGRAM HASH
 // ================================================================================================
 
@@ -3,9 +3,11 @@ use std::{path::PathBuf, time::Instant};
 use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use clap::Parser;
 use miden_vm::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program, ProgramInfo};
 
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
+use super::data::{
+    instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofBundle, ProofFile,
+};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Prove a miden program")]
@@ -14,6 +16,11 @@ pub struct ProveCmd {
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
+    /// Path to the proof bundle file containing the proof together with the program info and
+    /// the stack inputs and outputs needed to verify it
+    #[clap(short = 'b', long = "bundle", value_parser)]
+    bundle_file: Option<PathBuf>,
+
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
@@ -51,7 +58,12 @@ pub struct ProveCmd {
     rpx: bool,
 
     /// Security level for execution proofs generated by the VM
-    #[clap(short = 's', long = "security", default_value = "96bits")]
+    #[clap(
+        short = 's',
+        long = "security",
+        default_value = "96bits",
+        value_parser = ["96", "96bits", "128", "128bits"]
+    )]
     security: String,
 
     /// Enable tracing to monitor execution of the VM
@@ -64,14 +76,14 @@ impl ProveCmd {
         let exec_options =
             ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.trace, false)?;
         Ok(match self.security.as_str() {
-            "96bits" => {
+            "96" | "96bits" => {
                 if self.rpx {
                     ProvingOptions::with_96_bit_security_rpx()
                 } else {
                     ProvingOptions::with_96_bit_security(self.recursive)
                 }
             },
-            "128bits" => {
+            "128" | "128bits" => {
                 if self.rpx {
                     ProvingOptions::with_128_bit_security_rpx()
                 } else {
@@ -102,9 +114,10 @@ impl ProveCmd {
             self.get_proof_options().map_err(|err| Report::msg(format!("{err}")))?;
 
         // execute program and generate proof
-        let (stack_outputs, proof) = prover::prove(&program, stack_inputs, host, proving_options)
-            .into_diagnostic()
-            .wrap_err("Failed to prove program")?;
+        let (stack_outputs, proof) =
+            prover::prove(&program, stack_inputs.clone(), host, proving_options)
+                .into_diagnostic()
+                .wrap_err("Failed to prove program")?;
 
         println!(
             "Program with hash {} proved in {} ms",
@@ -112,6 +125,17 @@ impl ProveCmd {
             now.elapsed().as_millis()
         );
 
+        // write proof bundle to file, if requested
+        if let Some(bundle_path) = &self.bundle_file {
+            let bundle = ProofBundle {
+                program_info: ProgramInfo::from(program),
+                stack_inputs,
+                stack_outputs: stack_outputs.clone(),
+                proof: proof.clone(),
+            };
+            bundle.write(bundle_path).map_err(Report::msg)?;
+        }
+
         // write proof to file
         ProofFile::write(proof, &self.proof_file, &self.assembly_file).map_err(Report::msg)?;
 
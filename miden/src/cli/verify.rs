@@ -4,11 +4,17 @@ use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use clap::Parser;
 use miden_vm::{Kernel, ProgramInfo};
 
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
+use super::data::{InputFile, OutputFile, ProgramHash, ProofBundle, ProofFile};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Verify a miden program")]
 pub struct VerifyCmd {
+    /// Path to a proof bundle file produced by `prove --bundle`
+    #[clap(
+        value_parser,
+        conflicts_with_all(["input_file", "output_file", "proof_file", "program_hash"])
+    )]
+    bundle_file: Option<PathBuf>,
     /// Path to input file
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
@@ -16,50 +22,83 @@ pub struct VerifyCmd {
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
     /// Path to proof file
-    #[clap(short = 'p', long = "proof", value_parser)]
-    proof_file: PathBuf,
+    #[clap(
+        short = 'p',
+        long = "proof",
+        value_parser,
+        required_unless_present = "bundle_file"
+    )]
+    proof_file: Option<PathBuf>,
     /// Program hash (hex)
-    #[clap(short = 'h', long = "program-hash")]
-    program_hash: String,
+    #[clap(long = "program-hash", required_unless_present = "bundle_file")]
+    program_hash: Option<String>,
 }
 
 impl VerifyCmd {
     pub fn execute(&self) -> Result<(), Report> {
+        let ProofBundle {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+        } = match &self.bundle_file {
+            Some(bundle_file) => {
+                println!("===============================================================================");
+                println!("Verifying proof bundle: {}", bundle_file.display());
+                println!("-------------------------------------------------------------------------------");
+
+                ProofBundle::read(bundle_file).map_err(Report::msg)?
+            },
+            None => self.load_data()?,
+        };
+
+        let now = Instant::now();
+
+        // verify proof
+        let security_level = verifier::verify(program_info, stack_inputs, stack_outputs, proof)
+            .into_diagnostic()
+            .wrap_err("Program failed verification!")?;
+
+        println!("Verification complete in {} ms", now.elapsed().as_millis());
+        println!("Security level: {security_level} bits");
+
+        Ok(())
+    }
+
+    /// Loads the data needed for verification from the individual proof, input and output files.
+    fn load_data(&self) -> Result<ProofBundle, Report> {
+        let proof_file = self.proof_file.as_ref().expect("proof file must be provided");
+        let program_hash = self.program_hash.as_ref().expect("program hash must be provided");
+
         println!("===============================================================================");
-        println!("Verifying proof: {}", self.proof_file.display());
+        println!("Verifying proof: {}", proof_file.display());
         println!("-------------------------------------------------------------------------------");
 
         // read program hash from input
-        let program_hash = ProgramHash::read(&self.program_hash).map_err(Report::msg)?;
+        let program_hash = ProgramHash::read(program_hash).map_err(Report::msg)?;
 
         // load input data from file
-        let input_data = InputFile::read(&self.input_file, &self.proof_file)?;
+        let input_data = InputFile::read(&self.input_file, proof_file)?;
 
         // fetch the stack inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
 
         // load outputs data from file
-        let outputs_data =
-            OutputFile::read(&self.output_file, &self.proof_file).map_err(Report::msg)?;
+        let outputs_data = OutputFile::read(&self.output_file, proof_file).map_err(Report::msg)?;
+        let stack_outputs = outputs_data.stack_outputs().map_err(Report::msg)?;
 
         // load proof from file
-        let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)
-            .map_err(Report::msg)?;
-
-        let now = Instant::now();
+        let proof = ProofFile::read(&Some(proof_file.clone()), proof_file).map_err(Report::msg)?;
 
         // TODO accept kernel as CLI argument
         let kernel = Kernel::default();
         let program_info = ProgramInfo::new(program_hash, kernel);
 
-        // verify proof
-        let stack_outputs = outputs_data.stack_outputs().map_err(Report::msg)?;
-        verifier::verify(program_info, stack_inputs, stack_outputs, proof)
-            .into_diagnostic()
-            .wrap_err("Program failed verification!")?;
-
-        println!("Verification complete in {} ms", now.elapsed().as_millis());
-
-        Ok(())
+        Ok(ProofBundle {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+        })
     }
 }
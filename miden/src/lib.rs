@@ -16,6 +16,6 @@ pub use processor::{
 };
 pub use prover::{
     math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Proof,
-    ProvingOptions, StackOutputs, Word,
+    ProofParams, ProvingOptions, StackOutputs, Word,
 };
 pub use verifier::{verify, VerificationError};
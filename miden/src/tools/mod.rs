@@ -38,7 +38,8 @@ impl Analyze {
         // fetch the stack and program inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
         let mut host = DefaultHost::new(input_data.parse_advice_provider().map_err(Report::msg)?);
-        host.load_mast_forest(StdLibrary::default().mast_forest().clone());
+        host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+            .map_err(Report::msg)?;
 
         let execution_details: ExecutionDetails = analyze(program.as_str(), stack_inputs, host)
             .expect("Could not retrieve execution details");
@@ -153,9 +154,9 @@ impl fmt::Display for ExecutionDetails {
             f,
             "\nVM cycles: {} extended to {} steps ({}% padding).
 ├── Stack rows: {}
-├── Range checker rows: {}
+├── Range checker rows: {} ({} lookups)
 └── Chiplets rows: {}
-    ├── Hash chiplet rows: {}
+    ├── Hash chiplet rows: {} ({} permutations)
     ├── Bitwise chiplet rows: {}
     ├── Memory chiplet rows: {}
     └── Kernel ROM rows: {}\n",
@@ -164,8 +165,10 @@ impl fmt::Display for ExecutionDetails {
             padding_percentage,
             self.trace_len_summary().main_trace_len(),
             self.trace_len_summary().range_trace_len(),
+            self.trace_len_summary().num_range_check_lookups(),
             self.trace_len_summary().chiplets_trace_len().trace_len(),
             self.trace_len_summary().chiplets_trace_len().hash_chiplet_len(),
+            self.trace_len_summary().chiplets_trace_len().num_hash_permutations(),
             self.trace_len_summary().chiplets_trace_len().bitwise_chiplet_len(),
             self.trace_len_summary().chiplets_trace_len().memory_chiplet_len(),
             self.trace_len_summary().chiplets_trace_len().kernel_rom_len(),
@@ -312,6 +315,7 @@ mod tests {
             trace_len_summary: TraceLenSummary::new(
                 23,
                 39,
+                0,
                 ChipletsLengths::from_parts(8, 0, 2, 0),
             ),
         };
@@ -1,12 +1,16 @@
+use std::{fs, path::PathBuf};
+
 use assert_cmd::prelude::*;
+use miden_vm::{
+    utils::{Deserializable, SliceReader},
+    ExecutionProof, ProgramInfo, StackInputs, StackOutputs,
+};
 use predicates::prelude::*;
 extern crate escargot;
 
-#[test]
-// Tt test might be an overkill to test only that the 'run' cli command
-// outputs steps and ms.
-fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
-    let bin_under_test = escargot::CargoBuild::new()
+/// Builds the `miden` binary with the `executable` feature enabled and returns a handle to it.
+fn build_miden_bin() -> escargot::CargoRun {
+    escargot::CargoBuild::new()
         .bin("miden")
         .features("executable")
         .current_release()
@@ -15,7 +19,24 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|err| {
             eprintln!("{err}");
             panic!("failed to build `miden`");
-        });
+        })
+}
+
+/// Creates a fresh temporary directory for the test with the specified name.
+fn temp_test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("miden-cli-{name}-{}", std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+// Tt test might be an overkill to test only that the 'run' cli command
+// outputs steps and ms.
+fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
 
     let mut cmd = bin_under_test.command();
 
@@ -38,3 +59,189 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_analyze() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let mut cmd = bin_under_test.command();
+
+    cmd.arg("analyze").arg("-a").arg("./examples/fib/fib.masm");
+
+    let output = cmd.unwrap();
+
+    // the exact counts depend on the VM version, so only check that cycles and hasher
+    // permutations are reported and that the program performed at least one permutation
+    output
+        .assert()
+        .stdout(predicate::str::contains("VM cycles"))
+        .stdout(predicate::str::is_match(
+            r"Hash chiplet rows: [1-9]\d* \([1-9]\d* permutations\)",
+        )?)
+        .stdout(predicate::str::is_match(r"Range checker rows: [1-9]\d* \(\d+ lookups\)")?);
+
+    Ok(())
+}
+
+#[test]
+fn cli_bundle_reports_failing_module_path() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let lib_dir = temp_test_dir("bundle-error").join("mylib");
+    fs::create_dir_all(lib_dir.join("math"))?;
+    fs::write(lib_dir.join("good.masm"), "export.foo\n    push.1\nend\n")?;
+    fs::write(
+        lib_dir.join("math").join("u64.masm"),
+        "export.bar\n    push.1\n    foo bar\nend\n",
+    )?;
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("bundle").arg(&lib_dir);
+
+    let expected_path = PathBuf::from("math").join("u64.masm");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(format!("error in {}", expected_path.display())))
+        .stderr(predicate::str::contains("good.masm").not());
+
+    fs::remove_dir_all(lib_dir.parent().unwrap())?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_bundle_writes_source_map() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let lib_dir = temp_test_dir("bundle-source-map").join("mylib");
+    fs::create_dir_all(&lib_dir)?;
+    fs::write(lib_dir.join("foo.masm"), "export.foo\n    push.1\n    push.2 add\nend\n")?;
+    // `bar` has the same MAST root as `foo`
+    fs::write(lib_dir.join("bar.masm"), "\nexport.bar\n    push.1 push.2\n    add\nend\n")?;
+    let source_map_path = lib_dir.parent().unwrap().join("map.json");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("bundle").arg(&lib_dir).arg("--source-map").arg(&source_map_path);
+    cmd.assert().success();
+
+    // the first instruction of `foo` is on the second line of `foo.masm`
+    let source_map = fs::read_to_string(&source_map_path)?;
+    assert!(source_map.contains("\"procedure\": \"mylib::foo::foo\""), "{source_map}");
+    assert!(source_map.contains("foo.masm"), "{source_map}");
+    assert!(source_map.contains("\"line\": 2"), "{source_map}");
+
+    // both exports are listed under their shared MAST root
+    assert!(source_map.contains("\"procedure\": \"mylib::bar::bar\""), "{source_map}");
+    assert!(source_map.contains("bar.masm"), "{source_map}");
+    assert!(source_map.contains("\"line\": 3"), "{source_map}");
+    assert_eq!(source_map.matches("\"procedure\"").count(), 2, "{source_map}");
+    assert_eq!(source_map.matches(": [").count(), 1, "{source_map}");
+
+    fs::remove_dir_all(lib_dir.parent().unwrap())?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_bundle_writes_to_output_path() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let lib_dir = temp_test_dir("bundle-output").join("mylib");
+    fs::create_dir_all(&lib_dir)?;
+    fs::write(lib_dir.join("foo.masm"), "export.foo\n    push.1\nend\n")?;
+    let output_path = lib_dir.parent().unwrap().join("out").join("mylib.masl");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("bundle").arg(&lib_dir).arg("--output").arg(&output_path);
+    cmd.assert().success();
+    assert!(output_path.exists());
+
+    // a second write fails if overwriting is disabled
+    let mut cmd = bin_under_test.command();
+    cmd.arg("bundle")
+        .arg(&lib_dir)
+        .arg("--output")
+        .arg(&output_path)
+        .arg("--no-overwrite");
+    cmd.assert().failure();
+
+    fs::remove_dir_all(lib_dir.parent().unwrap())?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_prove_writes_verifiable_bundle() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let out_dir = temp_test_dir("prove-bundle");
+    let bundle_path = out_dir.join("fib.bundle");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-s")
+        .arg("96")
+        .arg("-p")
+        .arg(out_dir.join("fib.proof"))
+        .arg("-o")
+        .arg(out_dir.join("fib.outputs"))
+        .arg("-b")
+        .arg(&bundle_path);
+    cmd.assert().success();
+
+    // the bundle is laid out as program info, stack inputs, stack outputs and the proof
+    let bundle_bytes = fs::read(&bundle_path)?;
+    let mut reader = SliceReader::new(&bundle_bytes);
+    let program_info = ProgramInfo::read_from(&mut reader).unwrap();
+    let stack_inputs = StackInputs::read_from(&mut reader).unwrap();
+    let stack_outputs = StackOutputs::read_from(&mut reader).unwrap();
+    let proof = ExecutionProof::read_from(&mut reader).unwrap();
+
+    miden_vm::verify(program_info, stack_inputs, stack_outputs, proof)?;
+
+    fs::remove_dir_all(out_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_verify_bundle() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = build_miden_bin();
+
+    let out_dir = temp_test_dir("verify-bundle");
+    let bundle_path = out_dir.join("fib.bundle");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("prove")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-p")
+        .arg(out_dir.join("fib.proof"))
+        .arg("-o")
+        .arg(out_dir.join("fib.outputs"))
+        .arg("-b")
+        .arg(&bundle_path);
+    cmd.assert().success();
+
+    // a valid bundle should verify and report its security level
+    let mut cmd = bin_under_test.command();
+    cmd.arg("verify").arg(&bundle_path);
+    cmd.assert().success().stdout(predicate::str::contains("Security level"));
+
+    // flipping a byte in the middle of the proof should cause verification to fail
+    let mut bundle_bytes = fs::read(&bundle_path)?;
+    let idx = bundle_bytes.len() / 2;
+    bundle_bytes[idx] ^= 0xff;
+    let tampered_path = out_dir.join("tampered.bundle");
+    fs::write(&tampered_path, bundle_bytes)?;
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("verify").arg(&tampered_path);
+    cmd.assert().failure();
+
+    fs::remove_dir_all(out_dir)?;
+
+    Ok(())
+}
@@ -32,3 +32,99 @@ fn program_with_respan() {
 
     build_op_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
 }
+
+#[test]
+fn verification_error_reports_proof_context() {
+    use miden_vm::{
+        prove, verify, Assembler, DefaultHost, ProgramInfo, ProvingOptions, StackInputs,
+        VerificationError,
+    };
+
+    let program = Assembler::default()
+        .assemble_program("begin push.1 push.2 add swap drop end")
+        .unwrap();
+    let stack_inputs = StackInputs::default();
+    let (mut stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+    let security_level = proof.security_level();
+
+    // claim an incorrect result so that the proof does not verify
+    stack_outputs.stack_mut()[0] += miden_vm::math::Felt::new(1);
+    let err = verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof).unwrap_err();
+
+    let VerificationError::VerifierError { source, .. } = &err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(
+        err.to_string(),
+        format!("proof verification failed (Blake3_192, claimed {security_level}-bit): {source}")
+    );
+}
+
+#[test]
+fn program_to_masm_string() {
+    let source = std::fs::read_to_string("./examples/fib/fib.masm").unwrap();
+    let program = miden_vm::Assembler::default().assemble_program(source).unwrap();
+
+    // the body of the `repeat` block is unrolled into a single basic block of 3000 operations
+    let masm = program.to_masm_string();
+    assert!(masm.starts_with("begin"), "{masm}");
+    assert!(masm.ends_with("end"), "{masm}");
+    for op in ["swap", "dup1", "add"] {
+        assert!(masm.contains(op), "{masm}");
+    }
+}
+
+#[test]
+fn mast_forest_advice_map_is_loaded_into_host() {
+    use std::sync::Arc;
+
+    use miden_vm::{Assembler, DefaultHost, ExecutionError, StackInputs};
+    use test_utils::{ExecutionOptions, Felt};
+    use vm_core::{
+        crypto::hash::RpoDigest,
+        mast::{MastForest, MastForestError},
+        Operation,
+    };
+
+    let key = RpoDigest::try_from([1u64, 2, 3, 4]).unwrap();
+    let build_forest = |values: Vec<Felt>| {
+        let mut mast_forest = MastForest::new();
+        let block_id = mast_forest.add_block(vec![Operation::Noop], None).unwrap();
+        mast_forest.make_root(block_id);
+        mast_forest.advice_map_mut().insert(key, values);
+        Arc::new(mast_forest)
+    };
+    let values: Vec<Felt> = [8, 7, 6, 5].into_iter().map(Felt::new).collect();
+
+    let mut host = DefaultHost::default();
+    host.load_mast_forest(build_forest(values.clone())).unwrap();
+    // loading the same entries again is fine
+    host.load_mast_forest(build_forest(values)).unwrap();
+    // but the same key cannot map to different values
+    let err = host.load_mast_forest(build_forest(vec![Felt::new(9)])).unwrap_err();
+    assert_eq!(
+        err,
+        ExecutionError::MastForestLoadFailed(MastForestError::AdviceMapKeyCollisionOnMerge(key))
+    );
+
+    // the program reads the values from the advice map loaded with the forest
+    let source = "
+    begin
+        adv.push_mapval
+        dropw
+        adv_push.4
+        swapw dropw
+    end";
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3, 4]).unwrap();
+    let trace =
+        miden_vm::execute(&program, stack_inputs, host, ExecutionOptions::default()).unwrap();
+    let expected: Vec<Felt> = [5, 6, 7, 8].into_iter().map(Felt::new).collect();
+    assert_eq!(trace.stack_outputs().stack_truncated(4), expected);
+}
@@ -1,5 +1,12 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use assembly::Assembler;
 use miden_vm::{Digest, Word};
-use processor::ExecutionError;
+use processor::{
+    AdviceExtractor, DefaultHost, ExecutionError, ExecutionOptions, Host, HostResponse, MastForest,
+    MemAdviceProvider, ProcessState,
+};
+use prover::StackInputs;
 use rand_chacha::rand_core::SeedableRng;
 use test_utils::{
     build_test,
@@ -9,6 +16,7 @@ use test_utils::{
     serde::Serializable,
     Felt, TRUNCATE_STACK_PROC,
 };
+use vm_core::AdviceInjector;
 
 const ADVICE_PUSH_SIG: &str = "
     begin
@@ -500,3 +508,89 @@ fn advice_push_sig_rpo_falcon_512_bad_key_length() {
 
     expect_exec_error!(test, ExecutionError::MalformedSignatureKey("RPO Falcon512"));
 }
+
+// ADVICE MAP KEY MISSES
+// ================================================================================================
+
+/// A host which provides the values of advice map entries only when they are requested.
+struct LazyAdviceHost {
+    host: DefaultHost<MemAdviceProvider>,
+    lazy_entries: BTreeMap<RpoDigest, Vec<Felt>>,
+}
+
+impl Host for LazyAdviceHost {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.set_advice(process, injector)
+    }
+
+    fn get_mast_forest(&self, node_digest: &Digest) -> Option<Arc<MastForest>> {
+        self.host.get_mast_forest(node_digest)
+    }
+
+    fn on_advice_key_miss(&mut self, key: RpoDigest) -> Option<Vec<Felt>> {
+        self.lazy_entries.remove(&key)
+    }
+
+    fn insert_advice_map_entry(
+        &mut self,
+        key: RpoDigest,
+        values: Vec<Felt>,
+    ) -> Result<(), ExecutionError> {
+        self.host.insert_advice_map_entry(key, values)
+    }
+}
+
+#[test]
+fn advice_push_mapval_key_miss() {
+    let source: &str = "
+    begin
+        # stack: [4, 3, 2, 1, ...]
+
+        # load the advice stack with values from the advice map and drop the key
+        adv.push_mapval
+        dropw
+
+        # move the values from the advice stack to the operand stack
+        adv_push.4
+        swapw dropw
+    end";
+
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3, 4]).unwrap();
+    let key = RpoDigest::try_from([1u64, 2, 3, 4]).unwrap();
+
+    // by default, a missing key fails the execution
+    let result = processor::execute(
+        &program,
+        stack_inputs.clone(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+    assert!(matches!(result, Err(ExecutionError::AdviceMapKeyNotFound(k)) if k == Word::from(key)));
+
+    // a host can provide the values of a missing key on demand
+    let mut host = LazyAdviceHost {
+        host: DefaultHost::default(),
+        lazy_entries: BTreeMap::from([(
+            key,
+            vec![Felt::new(8), Felt::new(7), Felt::new(6), Felt::new(5)],
+        )]),
+    };
+    let trace =
+        processor::execute(&program, stack_inputs, &mut host, ExecutionOptions::default()).unwrap();
+    let expected = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    assert_eq!(trace.stack_outputs().stack_truncated(4), expected);
+    assert!(host.lazy_entries.is_empty());
+}
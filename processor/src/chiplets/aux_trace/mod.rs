@@ -1,9 +1,10 @@
 use alloc::vec::Vec;
+use core::ops::Range;
 
 use miden_air::{
     trace::{
         chiplets::{
-            bitwise::OP_CYCLE_LEN as BITWISE_OP_CYCLE_LEN,
+            bitwise::{BITWISE_AND, BITWISE_XOR, OP_CYCLE_LEN as BITWISE_OP_CYCLE_LEN},
             hasher::{
                 CAPACITY_LEN, DIGEST_RANGE, HASH_CYCLE_LEN, LINEAR_HASH_LABEL, MP_VERIFY_LABEL,
                 MR_UPDATE_NEW_LABEL, MR_UPDATE_OLD_LABEL, NUM_ROUNDS, RETURN_HASH_LABEL,
@@ -12,6 +13,7 @@ use miden_air::{
             kernel_rom::KERNEL_PROC_LABEL,
             memory::{MEMORY_READ_LABEL, MEMORY_WRITE_LABEL},
         },
+        decoder::NUM_USER_OP_HELPERS,
         main_trace::MainTrace,
     },
     RowIndex,
@@ -25,6 +27,9 @@ use vm_core::{
 
 use super::{super::trace::AuxColumnBuilder, Felt, FieldElement};
 
+#[cfg(test)]
+mod tests;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -87,7 +92,7 @@ impl ChipletsVTableColBuilder {
 impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for ChipletsVTableColBuilder {
     fn init_requests(&self, _main_trace: &MainTrace, alphas: &[E]) -> E {
         let mut requests = E::ONE;
-        for (addr, proc_hash) in self.kernel.proc_hashes().iter().enumerate() {
+        for (addr, proc_hash) in self.kernel.procedures() {
             requests *= alphas[0]
                 + alphas[1].mul_base((addr as u32).into())
                 + alphas[2].mul_base(proc_hash[0])
@@ -295,8 +300,8 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for BusColumnBuilder
             OPCODE_SPAN => build_span_block_request(main_trace, alphas, row),
             OPCODE_RESPAN => build_respan_block_request(main_trace, alphas, row),
             OPCODE_END => build_end_block_request(main_trace, alphas, row),
-            OPCODE_U32AND => build_bitwise_request(main_trace, ZERO, alphas, row),
-            OPCODE_U32XOR => build_bitwise_request(main_trace, ONE, alphas, row),
+            OPCODE_U32AND => build_bitwise_request(main_trace, BitwiseOp::And, alphas, row),
+            OPCODE_U32XOR => build_bitwise_request(main_trace, BitwiseOp::Xor, alphas, row),
             OPCODE_MLOADW => build_mem_request_word(main_trace, MEMORY_READ_LABEL, alphas, row),
             OPCODE_MSTOREW => build_mem_request_word(main_trace, MEMORY_WRITE_LABEL, alphas, row),
             OPCODE_MLOAD => build_mem_request_element(main_trace, MEMORY_READ_LABEL, alphas, row),
@@ -341,12 +346,10 @@ fn build_control_block_request<E: FieldElement<BaseField = Felt>>(
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let op_label = LINEAR_HASH_LABEL;
     let addr_nxt = main_trace.addr(row + 1);
-    let transition_label = op_label + 16;
+    let transition_label = transition_label(LINEAR_HASH_LABEL, addr_nxt);
 
-    let header =
-        alphas[0] + alphas[1].mul_base(Felt::from(transition_label)) + alphas[2].mul_base(addr_nxt);
+    let header = alphas[0] + alphas[1].mul_base(transition_label) + alphas[2].mul_base(addr_nxt);
 
     header + build_value(&alphas[8..16], &decoder_hasher_state) + alphas[5].mul_base(op_code_felt)
 }
@@ -404,12 +407,10 @@ fn build_span_block_request<E: FieldElement<BaseField = Felt>>(
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let op_label = LINEAR_HASH_LABEL;
     let addr_nxt = main_trace.addr(row + 1);
-    let transition_label = op_label + 16;
+    let transition_label = transition_label(LINEAR_HASH_LABEL, addr_nxt);
 
-    let header =
-        alphas[0] + alphas[1].mul_base(Felt::from(transition_label)) + alphas[2].mul_base(addr_nxt);
+    let header = alphas[0] + alphas[1].mul_base(transition_label) + alphas[2].mul_base(addr_nxt);
 
     let state = main_trace.decoder_hasher_state(row);
     header + build_value(&alphas[8..16], &state)
@@ -421,13 +422,13 @@ fn build_respan_block_request<E: FieldElement<BaseField = Felt>>(
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let op_label = LINEAR_HASH_LABEL;
-    let addr_nxt = main_trace.addr(row + 1);
-    let transition_label = op_label + 32;
+    // the request is made to the last row of the hash cycle preceding the one for the next batch
+    let addr = main_trace.addr(row + 1) - ONE;
+    let transition_label = transition_label(LINEAR_HASH_LABEL, addr);
 
     let header = alphas[0]
-        + alphas[1].mul_base(Felt::from(transition_label))
-        + alphas[2].mul_base(addr_nxt - ONE)
+        + alphas[1].mul_base(transition_label)
+        + alphas[2].mul_base(addr)
         + alphas[3].mul_base(ZERO);
 
     let state = main_trace.decoder_hasher_state(row);
@@ -441,12 +442,10 @@ fn build_end_block_request<E: FieldElement<BaseField = Felt>>(
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let op_label = RETURN_HASH_LABEL;
     let addr = main_trace.addr(row) + Felt::from(NUM_ROUNDS as u8);
-    let transition_label = op_label + 32;
+    let transition_label = transition_label(RETURN_HASH_LABEL, addr);
 
-    let header =
-        alphas[0] + alphas[1].mul_base(Felt::from(transition_label)) + alphas[2].mul_base(addr);
+    let header = alphas[0] + alphas[1].mul_base(transition_label) + alphas[2].mul_base(addr);
 
     let state = main_trace.decoder_hasher_state(row);
     let digest = &state[..4];
@@ -454,15 +453,15 @@ fn build_end_block_request<E: FieldElement<BaseField = Felt>>(
     header + build_value(&alphas[8..12], digest)
 }
 
-/// Builds requests made to the bitwise chiplet. This can be either a request for the computation
-/// of a `XOR` or an `AND` operation.
+/// Builds requests made to the bitwise chiplet for the computation of the specified bitwise
+/// operation.
 fn build_bitwise_request<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
-    is_xor: Felt,
+    op: BitwiseOp,
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let op_label = get_op_label(ONE, ZERO, is_xor, ZERO);
+    let op_label = op.op_label();
     let a = main_trace.stack_element(1, row);
     let b = main_trace.stack_element(0, row);
     let z = main_trace.stack_element(0, row + 1);
@@ -475,6 +474,10 @@ fn build_bitwise_request<E: FieldElement<BaseField = Felt>>(
 }
 
 /// Builds `MLOAD` and `MSTORE` requests made to the memory chiplet.
+///
+/// The address is read from the top of the stack and the element from the top of the stack in the
+/// next row; the remaining elements of the word are provided via the decoder helper registers.
+/// Memory requests never read from the stack overflow table.
 fn build_mem_request_element<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
     op_label: u8,
@@ -493,6 +496,9 @@ fn build_mem_request_element<E: FieldElement<BaseField = Felt>>(
 }
 
 /// Builds `MLOADW` and `MSTOREW` requests made to the memory chiplet.
+///
+/// The address is read from the top of the stack and the word from the top 4 stack elements in the
+/// next row.
 fn build_mem_request_word<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
     op_label: u8,
@@ -570,12 +576,19 @@ fn build_rcomb_base_request<E: FieldElement<BaseField = Felt>>(
     alphas: &[E],
     row: RowIndex,
 ) -> E {
-    let tz0 = main_trace.helper_register(0, row);
-    let tz1 = main_trace.helper_register(1, row);
-    let tzg0 = main_trace.helper_register(2, row);
-    let tzg1 = main_trace.helper_register(3, row);
-    let a0 = main_trace.helper_register(4, row);
-    let a1 = main_trace.helper_register(5, row);
+    debug_assert_eq!(
+        main_trace.get_op_code(row),
+        Felt::from(OPCODE_RCOMBBASE),
+        "RCOMBBASE request built for a row which does not execute RCOMBBASE"
+    );
+    // `RCOMBBASE` places the values it reads from memory into the helper registers as
+    // [tz0, tz1, tzg0, tzg1, a0, a1] (see `Process::op_rcomb_base()`), where (tz0, tz1) and
+    // (tzg0, tzg1) are the coordinates of T_i(z) and T_i(gz) read from the word at `z_ptr`, and
+    // (a0, a1) are the coordinates of alpha_i read from the word at `a_ptr`
+    let [tz0, tz1, tzg0, tzg1] =
+        [0, 1, 2, 3].map(|i| main_trace.helper_register(RCOMB_BASE_OOD_HELPERS.start + i, row));
+    let [a0, a1] =
+        [0, 1].map(|i| main_trace.helper_register(RCOMB_BASE_ALPHA_HELPERS.start + i, row));
     let z_ptr = main_trace.stack_element(13, row);
     let a_ptr = main_trace.stack_element(14, row);
     let op_label = MEMORY_READ_LABEL;
@@ -588,6 +601,17 @@ fn build_rcomb_base_request<E: FieldElement<BaseField = Felt>>(
     factor1 * factor2
 }
 
+/// Helper registers holding the coordinates of T_i(z) and T_i(gz) read from memory by `RCOMBBASE`.
+const RCOMB_BASE_OOD_HELPERS: Range<usize> = 0..4;
+
+/// Helper registers holding the coordinates of alpha_i read from memory by `RCOMBBASE`.
+const RCOMB_BASE_ALPHA_HELPERS: Range<usize> = 4..6;
+
+const _: () = assert!(
+    RCOMB_BASE_OOD_HELPERS.end == RCOMB_BASE_ALPHA_HELPERS.start
+        && RCOMB_BASE_ALPHA_HELPERS.end <= NUM_USER_OP_HELPERS
+);
+
 /// Builds `HPERM` requests made to the hash chiplet.
 fn build_hperm_request<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
@@ -596,58 +620,30 @@ fn build_hperm_request<E: FieldElement<BaseField = Felt>>(
 ) -> E {
     let helper_0 = main_trace.helper_register(0, row);
 
-    let s0_s12_cur = [
-        main_trace.stack_element(0, row),
-        main_trace.stack_element(1, row),
-        main_trace.stack_element(2, row),
-        main_trace.stack_element(3, row),
-        main_trace.stack_element(4, row),
-        main_trace.stack_element(5, row),
-        main_trace.stack_element(6, row),
-        main_trace.stack_element(7, row),
-        main_trace.stack_element(8, row),
-        main_trace.stack_element(9, row),
-        main_trace.stack_element(10, row),
-        main_trace.stack_element(11, row),
-    ];
+    let s0_s12_cur = main_trace.stack_range::<12>(0, row);
 
-    let s0_s12_nxt = [
-        main_trace.stack_element(0, row + 1),
-        main_trace.stack_element(1, row + 1),
-        main_trace.stack_element(2, row + 1),
-        main_trace.stack_element(3, row + 1),
-        main_trace.stack_element(4, row + 1),
-        main_trace.stack_element(5, row + 1),
-        main_trace.stack_element(6, row + 1),
-        main_trace.stack_element(7, row + 1),
-        main_trace.stack_element(8, row + 1),
-        main_trace.stack_element(9, row + 1),
-        main_trace.stack_element(10, row + 1),
-        main_trace.stack_element(11, row + 1),
-    ];
-
-    let op_label = LINEAR_HASH_LABEL + 16;
+    let s0_s12_nxt = main_trace.stack_range::<12>(0, row + 1);
 
+    let addr_input = helper_0;
     let sum_input = alphas[4..16]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s0_s12_cur[i]));
     let v_input = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0)
+        + alphas[1].mul_base(transition_label(LINEAR_HASH_LABEL, addr_input))
+        + alphas[2].mul_base(addr_input)
         + sum_input;
 
-    let op_label = RETURN_STATE_LABEL + 32;
-
+    let addr_output = helper_0 + Felt::new(7);
     let sum_output = alphas[4..16]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s0_s12_nxt[i]));
     let v_output = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0 + Felt::new(7))
+        + alphas[1].mul_base(transition_label(RETURN_STATE_LABEL, addr_output))
+        + alphas[2].mul_base(addr_output)
         + sum_output;
 
     v_input * v_output
@@ -661,23 +657,12 @@ fn build_mpverify_request<E: FieldElement<BaseField = Felt>>(
 ) -> E {
     let helper_0 = main_trace.helper_register(0, row);
 
-    let s0_s3 = [
-        main_trace.stack_element(0, row),
-        main_trace.stack_element(1, row),
-        main_trace.stack_element(2, row),
-        main_trace.stack_element(3, row),
-    ];
+    let s0_s3 = main_trace.stack_word(0, row);
     let s4 = main_trace.stack_element(4, row);
     let s5 = main_trace.stack_element(5, row);
-    let s6_s9 = [
-        main_trace.stack_element(6, row),
-        main_trace.stack_element(7, row),
-        main_trace.stack_element(8, row),
-        main_trace.stack_element(9, row),
-    ];
-
-    let op_label = MP_VERIFY_LABEL + 16;
+    let s6_s9 = main_trace.stack_word(6, row);
 
+    let addr_input = helper_0;
     let sum_input = alphas[8..12]
         .iter()
         .rev()
@@ -685,21 +670,20 @@ fn build_mpverify_request<E: FieldElement<BaseField = Felt>>(
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s0_s3[i]));
 
     let v_input = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0)
+        + alphas[1].mul_base(transition_label(MP_VERIFY_LABEL, addr_input))
+        + alphas[2].mul_base(addr_input)
         + alphas[3].mul_base(s5)
         + sum_input;
 
-    let op_label = RETURN_HASH_LABEL + 32;
-
+    let addr_output = helper_0 + s4.mul_small(8) - ONE;
     let sum_output = alphas[8..12]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s6_s9[i]));
     let v_output = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0 + s4.mul_small(8) - ONE)
+        + alphas[1].mul_base(transition_label(RETURN_HASH_LABEL, addr_output))
+        + alphas[2].mul_base(addr_output)
         + sum_output;
 
     v_input * v_output
@@ -713,80 +697,57 @@ fn build_mrupdate_request<E: FieldElement<BaseField = Felt>>(
 ) -> E {
     let helper_0 = main_trace.helper_register(0, row);
 
-    let s0_s3 = [
-        main_trace.stack_element(0, row),
-        main_trace.stack_element(1, row),
-        main_trace.stack_element(2, row),
-        main_trace.stack_element(3, row),
-    ];
-    let s0_s3_nxt = [
-        main_trace.stack_element(0, row + 1),
-        main_trace.stack_element(1, row + 1),
-        main_trace.stack_element(2, row + 1),
-        main_trace.stack_element(3, row + 1),
-    ];
+    let s0_s3 = main_trace.stack_word(0, row);
+    let s0_s3_nxt = main_trace.stack_word(0, row + 1);
     let s4 = main_trace.stack_element(4, row);
     let s5 = main_trace.stack_element(5, row);
-    let s6_s9 = [
-        main_trace.stack_element(6, row),
-        main_trace.stack_element(7, row),
-        main_trace.stack_element(8, row),
-        main_trace.stack_element(9, row),
-    ];
-    let s10_s13 = [
-        main_trace.stack_element(10, row),
-        main_trace.stack_element(11, row),
-        main_trace.stack_element(12, row),
-        main_trace.stack_element(13, row),
-    ];
-
-    let op_label = MR_UPDATE_OLD_LABEL + 16;
+    let s6_s9 = main_trace.stack_word(6, row);
+    let s10_s13 = main_trace.stack_word(10, row);
 
+    let addr_input_old = helper_0;
     let sum_input = alphas[8..12]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s0_s3[i]));
     let v_input_old = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0)
+        + alphas[1].mul_base(transition_label(MR_UPDATE_OLD_LABEL, addr_input_old))
+        + alphas[2].mul_base(addr_input_old)
         + alphas[3].mul_base(s5)
         + sum_input;
 
-    let op_label = RETURN_HASH_LABEL + 32;
-
+    let addr_output_old = helper_0 + s4.mul_small(8) - ONE;
     let sum_output = alphas[8..12]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s6_s9[i]));
     let v_output_old = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0 + s4.mul_small(8) - ONE)
+        + alphas[1].mul_base(transition_label(RETURN_HASH_LABEL, addr_output_old))
+        + alphas[2].mul_base(addr_output_old)
         + sum_output;
 
-    let op_label = MR_UPDATE_NEW_LABEL + 16;
+    let addr_input_new = helper_0 + s4.mul_small(8);
     let sum_input = alphas[8..12]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s10_s13[i]));
     let v_input_new = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0 + s4.mul_small(8))
+        + alphas[1].mul_base(transition_label(MR_UPDATE_NEW_LABEL, addr_input_new))
+        + alphas[2].mul_base(addr_input_new)
         + alphas[3].mul_base(s5)
         + sum_input;
 
-    let op_label = RETURN_HASH_LABEL + 32;
-
+    let addr_output_new = helper_0 + s4.mul_small(16) - ONE;
     let sum_output = alphas[8..12]
         .iter()
         .rev()
         .enumerate()
         .fold(E::ZERO, |acc, (i, x)| acc + x.mul_base(s0_s3_nxt[i]));
     let v_output_new = alphas[0]
-        + alphas[1].mul_base(Felt::from(op_label))
-        + alphas[2].mul_base(helper_0 + s4.mul_small(16) - ONE)
+        + alphas[1].mul_base(transition_label(RETURN_HASH_LABEL, addr_output_new))
+        + alphas[2].mul_base(addr_output_new)
         + sum_output;
 
     v_input_new * v_input_old * v_output_new * v_output_old
@@ -896,9 +857,9 @@ fn build_bitwise_chiplet_responses<E>(main_trace: &MainTrace, row: RowIndex, alp
 where
     E: FieldElement<BaseField = Felt>,
 {
-    let is_xor = main_trace.chiplet_selector_2(row);
+    let op_selector = main_trace.chiplet_selector_2(row);
     if row.as_usize() % BITWISE_OP_CYCLE_LEN == BITWISE_OP_CYCLE_LEN - 1 {
-        let op_label = get_op_label(ONE, ZERO, is_xor, ZERO);
+        let op_label = get_bitwise_op_label(op_selector);
 
         let a = main_trace.chiplet_bitwise_a(row);
         let b = main_trace.chiplet_bitwise_b(row);
@@ -925,10 +886,7 @@ where
     let ctx = main_trace.chiplet_memory_ctx(row);
     let clk = main_trace.chiplet_memory_clk(row);
     let addr = main_trace.chiplet_memory_addr(row);
-    let value0 = main_trace.chiplet_memory_value_0(row);
-    let value1 = main_trace.chiplet_memory_value_1(row);
-    let value2 = main_trace.chiplet_memory_value_2(row);
-    let value3 = main_trace.chiplet_memory_value_3(row);
+    let [value0, value1, value2, value3] = main_trace.chiplet_memory_word(row);
 
     alphas[0]
         + alphas[1].mul_base(op_label)
@@ -964,14 +922,49 @@ where
     v.mul_base(kernel_chiplet_selector) + E::from(ONE - kernel_chiplet_selector)
 }
 
+// BITWISE OPERATIONS
+// ================================================================================================
+
+/// Operations which can be requested from the bitwise chiplet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BitwiseOp {
+    And,
+    Xor,
+}
+
+impl BitwiseOp {
+    /// Returns the value of the operation selector column of the bitwise chiplet for this
+    /// operation.
+    fn selector(self) -> Felt {
+        match self {
+            Self::And => BITWISE_AND,
+            Self::Xor => BITWISE_XOR,
+        }
+    }
+
+    /// Returns the unique label of this operation.
+    fn op_label(self) -> Felt {
+        get_bitwise_op_label(self.selector())
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 /// Reduces a slice of elements to a single field element in the field specified by E using a slice
 /// of alphas of matching length. This can be used to build the value for a single word or for an
 /// entire [HasherState].
+///
+/// # Panics
+/// Panics if the number of alphas is not equal to the number of elements.
 fn build_value<E: FieldElement<BaseField = Felt>>(alphas: &[E], elements: &[Felt]) -> E {
-    assert_eq!(alphas.len(), elements.len());
+    assert_eq!(
+        alphas.len(),
+        elements.len(),
+        "number of alphas ({}) does not match the number of elements ({})",
+        alphas.len(),
+        elements.len()
+    );
     let mut value = E::ZERO;
     for (&alpha, &element) in alphas.iter().zip(elements.iter()) {
         value += alpha.mul_base(element);
@@ -980,10 +973,37 @@ fn build_value<E: FieldElement<BaseField = Felt>>(alphas: &[E], elements: &[Felt
 }
 
 /// Returns the operation unique label.
+///
+/// The labels of the operations performed by the chiplets are listed in
+/// [miden_air::trace::chiplets::ChipletLabel].
 fn get_op_label(s0: Felt, s1: Felt, s2: Felt, s3: Felt) -> Felt {
     s3.mul_small(1 << 3) + s2.mul_small(1 << 2) + s1.mul_small(2) + s0 + ONE
 }
 
+/// Returns the label of a message exchanged with the hasher chiplet at the hasher row with address
+/// `addr`, for the operation with the specified base label.
+///
+/// The hasher row with address `addr` is at index `addr - 1` of the hasher trace. Messages on the
+/// first row of a hash cycle are labeled `base_label + 16`, and messages on the last row of a hash
+/// cycle are labeled `base_label + 32`.
+///
+/// # Panics
+/// Panics if `addr` does not refer to the first or the last row of a hash cycle.
+fn transition_label(base_label: u8, addr: Felt) -> Felt {
+    let row = addr.as_int().checked_sub(1).expect("hasher row addresses start at 1");
+    match row % HASH_CYCLE_LEN as u64 {
+        0 => Felt::from(base_label + 16),
+        cycle_row if cycle_row == HASH_CYCLE_LEN as u64 - 1 => Felt::from(base_label + 32),
+        cycle_row => panic!("no message is exchanged on row {cycle_row} of a hash cycle"),
+    }
+}
+
+/// Returns the unique label of the bitwise chiplet operation with the specified operation
+/// selector.
+fn get_bitwise_op_label(op_selector: Felt) -> Felt {
+    get_op_label(ONE, ZERO, op_selector, ZERO)
+}
+
 /// Computes a memory read or write request at `row` given randomness `alphas`, memory address
 /// `addr` and value `value`.
 fn compute_memory_request<E: FieldElement<BaseField = Felt>>(
@@ -0,0 +1,491 @@
+use alloc::vec::Vec;
+
+use miden_air::trace::{
+    chiplets::{
+        bitwise::{BITWISE_AND_LABEL, BITWISE_XOR_LABEL},
+        ChipletLabel, MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX,
+        MEMORY_V_COL_RANGE,
+    },
+    decoder::{NUM_OP_BITS, OP_BITS_OFFSET, USER_OP_HELPERS_OFFSET},
+    main_trace::MainTrace,
+    stack::{B0_COL_IDX, B1_COL_IDX, H0_COL_IDX},
+    CHIPLETS_OFFSET, CLK_COL_IDX, CTX_COL_IDX, DECODER_TRACE_OFFSET, STACK_TRACE_OFFSET,
+    TRACE_WIDTH,
+};
+use test_utils::rand::rand_array;
+
+use super::*;
+use crate::ColMatrix;
+
+// MEMORY REQUESTS
+// ================================================================================================
+
+#[test]
+fn mem_request_element_reads_value_and_helpers() {
+    let alphas = rand_array::<Felt, 9>();
+    let row = RowIndex::from(2);
+    let addr = Felt::new(7);
+    let value = Felt::new(11);
+    let helpers = [Felt::new(3), Felt::new(5), Felt::new(9)];
+
+    let mut fixture = MainTraceFixture::new(4);
+    fixture.set_system(row, Felt::new(2), Felt::new(1));
+    fixture.set_stack(row, &[addr]);
+    fixture.set_stack(row + 1, &[value]);
+    fixture.set_helpers(row, &helpers);
+    let main_trace = fixture.build();
+
+    // the loaded element is the first element of the word, and the remaining elements of the word
+    // are provided via the helper registers in reverse order
+    let word = [value, helpers[2], helpers[1], helpers[0]];
+    let expected = build_expected_memory_request(&alphas, MEMORY_READ_LABEL, 1, 2, addr, word);
+    let request = build_mem_request_element(&main_trace, MEMORY_READ_LABEL, &alphas, row);
+    assert_eq!(expected, request);
+}
+
+#[test]
+fn mem_request_word_reads_top_word_of_next_row() {
+    let alphas = rand_array::<Felt, 9>();
+    let row = RowIndex::from(1);
+    let addr = Felt::new(4);
+    let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let mut fixture = MainTraceFixture::new(4);
+    fixture.set_system(row, Felt::new(1), ZERO);
+    fixture.set_stack(row, &[addr]);
+    // the word is laid out on the stack with its first element deepest
+    fixture.set_stack(row + 1, &[word[3], word[2], word[1], word[0]]);
+    let main_trace = fixture.build();
+
+    let expected = build_expected_memory_request(&alphas, MEMORY_WRITE_LABEL, 0, 1, addr, word);
+    let request = build_mem_request_word(&main_trace, MEMORY_WRITE_LABEL, &alphas, row);
+    assert_eq!(expected, request);
+}
+
+/// Memory requests only ever read the address and values from the top of the stack (and the
+/// decoder helper registers), so the state of the stack overflow table must have no influence on
+/// them, even when the stack is deeper than its top 16 elements.
+#[test]
+fn mem_requests_ignore_stack_overflow() {
+    let alphas = rand_array::<Felt, 9>();
+    let row = RowIndex::from(1);
+    let stack = (1..=16).map(Felt::new).collect::<Vec<_>>();
+    let next_stack = (17..=32).map(Felt::new).collect::<Vec<_>>();
+    let helpers = [Felt::new(33), Felt::new(34), Felt::new(35)];
+
+    let build_trace = |depth: u64, overflow_addr: u64| {
+        let mut fixture = MainTraceFixture::new(4);
+        fixture.set_system(row, Felt::new(1), ZERO);
+        fixture.set_stack(row, &stack);
+        fixture.set_stack(row + 1, &next_stack);
+        fixture.set_helpers(row, &helpers);
+        fixture.set_overflow(row, depth, overflow_addr);
+        fixture.set_overflow(row + 1, depth, overflow_addr);
+        fixture.build()
+    };
+    let no_overflow = build_trace(16, 0);
+    let with_overflow = build_trace(20, 42);
+    assert!(!no_overflow.is_non_empty_overflow(row));
+    assert!(with_overflow.is_non_empty_overflow(row));
+
+    for op_label in [MEMORY_READ_LABEL, MEMORY_WRITE_LABEL] {
+        assert_eq!(
+            build_mem_request_element(&no_overflow, op_label, &alphas, row),
+            build_mem_request_element(&with_overflow, op_label, &alphas, row)
+        );
+        assert_eq!(
+            build_mem_request_word(&no_overflow, op_label, &alphas, row),
+            build_mem_request_word(&with_overflow, op_label, &alphas, row)
+        );
+    }
+    assert_eq!(
+        build_mstream_request(&no_overflow, &alphas, row),
+        build_mstream_request(&with_overflow, &alphas, row)
+    );
+    assert_eq!(
+        build_pipe_request(&no_overflow, &alphas, row),
+        build_pipe_request(&with_overflow, &alphas, row)
+    );
+}
+
+#[test]
+fn chiplet_memory_word_matches_value_columns() {
+    let row = RowIndex::from(2);
+    let word = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+
+    let mut fixture = MainTraceFixture::new(4);
+    fixture.set_memory_access(row, false, ONE, Felt::new(5), Felt::new(7), word);
+    let main_trace = fixture.build();
+
+    assert_eq!(main_trace.chiplet_memory_word(row), word);
+    assert_eq!(
+        main_trace.chiplet_memory_word(row),
+        [
+            main_trace.chiplet_memory_value_0(row),
+            main_trace.chiplet_memory_value_1(row),
+            main_trace.chiplet_memory_value_2(row),
+            main_trace.chiplet_memory_value_3(row),
+        ]
+    );
+}
+
+/// `MSTREAM` reads two consecutive words from memory, so its request must cancel out the responses
+/// of the memory chiplet for the two reads.
+#[test]
+fn mstream_request_matches_memory_responses() {
+    let alphas = rand_array::<Felt, 9>();
+    let row = RowIndex::from(1);
+    let ctx = Felt::new(3);
+    let clk = Felt::new(1);
+    let addr = Felt::new(20);
+    let word1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let word2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let mut fixture = MainTraceFixture::new(8);
+    fixture.set_system(row, clk, ctx);
+    // the address is in s12 before the operation is executed
+    let mut stack = [ZERO; 13];
+    stack[12] = addr;
+    fixture.set_stack(row, &stack);
+    // the two words are placed at the top of the stack with the first word below the second one,
+    // and the first element of each word deepest
+    fixture.set_stack(
+        row + 1,
+        &[word2[3], word2[2], word2[1], word2[0], word1[3], word1[2], word1[1], word1[0]],
+    );
+    // the memory chiplet reads the two words from consecutive addresses
+    let response_rows = [RowIndex::from(4), RowIndex::from(5)];
+    fixture.set_memory_access(response_rows[0], true, ctx, clk, addr, word1);
+    fixture.set_memory_access(response_rows[1], true, ctx, clk, addr + ONE, word2);
+    let main_trace = fixture.build();
+
+    let expected = build_memory_chiplet_responses(&main_trace, response_rows[0], &alphas)
+        * build_memory_chiplet_responses(&main_trace, response_rows[1], &alphas);
+    assert_eq!(expected, build_mstream_request(&main_trace, &alphas, row));
+}
+
+/// `RCOMBBASE` reads the OOD values and the randomness from memory into the helper registers, so
+/// its request must cancel out the responses of the memory chiplet for the two reads.
+#[test]
+fn rcomb_base_request_matches_memory_responses() {
+    let alphas = rand_array::<Felt, 9>();
+    let row = RowIndex::from(1);
+    let ctx = Felt::new(2);
+    let clk = Felt::new(1);
+    let z_ptr = Felt::new(40);
+    let a_ptr = Felt::new(50);
+    let [tz0, tz1, tgz0, tgz1, a0, a1] = rand_array::<Felt, 6>();
+
+    let mut fixture = MainTraceFixture::new(8);
+    fixture.set_system(row, clk, ctx);
+    fixture.set_op_code(row, OPCODE_RCOMBBASE);
+    // the pointers to the OOD values and to the randomness are in s13 and s14, respectively
+    let mut stack = [ZERO; 15];
+    stack[13] = z_ptr;
+    stack[14] = a_ptr;
+    fixture.set_stack(row, &stack);
+    fixture.set_helpers(row, &[tz0, tz1, tgz0, tgz1, a0, a1]);
+    // the memory chiplet reads [T_i(z), T_i(gz)] at `z_ptr` and [alpha_i, 0, 0] at `a_ptr`
+    let response_rows = [RowIndex::from(4), RowIndex::from(5)];
+    fixture.set_memory_access(response_rows[0], true, ctx, clk, z_ptr, [tz0, tz1, tgz0, tgz1]);
+    fixture.set_memory_access(response_rows[1], true, ctx, clk, a_ptr, [a0, a1, ZERO, ZERO]);
+    let main_trace = fixture.build();
+
+    let expected = build_memory_chiplet_responses(&main_trace, response_rows[0], &alphas)
+        * build_memory_chiplet_responses(&main_trace, response_rows[1], &alphas);
+    assert_eq!(expected, build_rcomb_base_request(&main_trace, &alphas, row));
+}
+
+// LABELS
+// ================================================================================================
+
+#[test]
+fn op_labels_match_chiplet_labels() {
+    for label in ChipletLabel::ALL {
+        let [s0, s1, s2, s3] = label.selectors();
+        assert_eq!(get_op_label(s0, s1, s2, s3), label.as_felt(), "{label:?}");
+    }
+}
+
+// KERNEL ROM
+// ================================================================================================
+
+#[test]
+fn kernel_row_predicates() {
+    let root1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let root2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let mut fixture = MainTraceFixture::new(8);
+    // the first two kernel ROM rows share the same address, followed by a row with an address
+    // change, and a padding row
+    fixture.set_kernel_rom_row(RowIndex::from(1), ZERO, root1);
+    fixture.set_kernel_rom_row(RowIndex::from(2), ZERO, root1);
+    fixture.set_kernel_rom_row(RowIndex::from(3), ONE, root2);
+    fixture.set_chiplets_padding_row(RowIndex::from(4));
+    // the block address of the decoder changes only between rows 4 and 5
+    fixture.set_block_addr(RowIndex::from(5), Felt::new(8));
+    let main_trace = fixture.build();
+
+    let is_kernel_row: Vec<_> = (0..6).map(|i| main_trace.is_kernel_row(i.into())).collect();
+    assert_eq!(is_kernel_row, [false, true, true, true, false, false]);
+
+    let is_addr_change: Vec<_> = (0..5).map(|i| main_trace.is_addr_change(i.into())).collect();
+    assert_eq!(is_addr_change, [false, false, false, false, true]);
+}
+
+#[test]
+fn kernel_procedure_table_inclusions() {
+    let alphas = rand_array::<Felt, 6>();
+    let root1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let root2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let mut fixture = MainTraceFixture::new(8);
+    fixture.set_kernel_rom_row(RowIndex::from(0), ZERO, root1);
+    fixture.set_kernel_rom_row(RowIndex::from(1), ZERO, root1);
+    fixture.set_kernel_rom_row(RowIndex::from(2), ONE, root2);
+    fixture.set_chiplets_padding_row(RowIndex::from(3));
+    let main_trace = fixture.build();
+
+    let inclusion = |addr: Felt, root: Word| {
+        alphas[0]
+            + alphas[1] * addr
+            + alphas[2] * root[0]
+            + alphas[3] * root[1]
+            + alphas[4] * root[2]
+            + alphas[5] * root[3]
+    };
+    let inclusions: Vec<_> = (0..4)
+        .map(|i| build_kernel_procedure_table_inclusions(&main_trace, &alphas, i.into()))
+        .collect();
+
+    // a kernel row is included only if it is the last row for its address, i.e., if the address
+    // changes in the next row or the next row is not a kernel row
+    assert_eq!(inclusions, [ONE, inclusion(ZERO, root1), inclusion(ONE, root2), ONE]);
+}
+
+// STACK HELPERS
+// ================================================================================================
+
+#[test]
+fn stack_word_and_range_match_element_reads() {
+    let row = RowIndex::from(1);
+    let stack: Vec<Felt> = (1..=16).map(Felt::new).collect();
+
+    let mut fixture = MainTraceFixture::new(4);
+    fixture.set_stack(row, &stack);
+    let main_trace = fixture.build();
+
+    let elements: Vec<_> = (0..16).map(|i| main_trace.stack_element(i, row)).collect();
+    assert_eq!(main_trace.stack_word(0, row), elements[0..4]);
+    assert_eq!(main_trace.stack_word(6, row), elements[6..10]);
+    assert_eq!(main_trace.stack_range::<12>(0, row), elements[0..12]);
+    assert_eq!(main_trace.stack_range::<0>(3, row), []);
+}
+
+// BITWISE REQUESTS
+// ================================================================================================
+
+#[test]
+fn transition_labels() {
+    // the first and last rows of the first hash cycle have addresses 1 and 8
+    assert_eq!(transition_label(LINEAR_HASH_LABEL, ONE), Felt::from(LINEAR_HASH_LABEL + 16));
+    assert_eq!(
+        transition_label(RETURN_HASH_LABEL, Felt::new(8)),
+        Felt::from(RETURN_HASH_LABEL + 32)
+    );
+
+    // the first and last rows of the third hash cycle
+    assert_eq!(
+        transition_label(MP_VERIFY_LABEL, Felt::new(17)),
+        Felt::from(MP_VERIFY_LABEL + 16)
+    );
+    assert_eq!(
+        transition_label(RETURN_STATE_LABEL, Felt::new(24)),
+        Felt::from(RETURN_STATE_LABEL + 32)
+    );
+}
+
+#[test]
+#[should_panic(expected = "no message is exchanged on row 3 of a hash cycle")]
+fn transition_label_inner_row() {
+    transition_label(LINEAR_HASH_LABEL, Felt::new(12));
+}
+
+#[test]
+fn bitwise_op_labels() {
+    assert_eq!(BitwiseOp::And.op_label(), BITWISE_AND_LABEL);
+    assert_eq!(BitwiseOp::Xor.op_label(), BITWISE_XOR_LABEL);
+}
+
+#[test]
+fn bitwise_requests() {
+    let alphas = rand_array::<Felt, 5>();
+    let row = RowIndex::from(1);
+    let a = Felt::new(0b1100);
+    let b = Felt::new(0b1010);
+
+    for (op, op_label, z) in [
+        (BitwiseOp::And, BITWISE_AND_LABEL, Felt::new(0b1000)),
+        (BitwiseOp::Xor, BITWISE_XOR_LABEL, Felt::new(0b0110)),
+    ] {
+        // the operands are at the top of the stack, and the result is at the top of the stack in
+        // the next row
+        let mut fixture = MainTraceFixture::new(4);
+        fixture.set_stack(row, &[b, a]);
+        fixture.set_stack(row + 1, &[z]);
+        let main_trace = fixture.build();
+
+        let expected =
+            alphas[0] + alphas[1] * op_label + alphas[2] * a + alphas[3] * b + alphas[4] * z;
+        assert_eq!(expected, build_bitwise_request(&main_trace, op, &alphas, row));
+    }
+}
+
+// VALUE REDUCTION
+// ================================================================================================
+
+#[test]
+fn build_value_reduces_elements() {
+    let alphas = rand_array::<Felt, 4>();
+    let elements = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let expected = alphas[0] * elements[0]
+        + alphas[1] * elements[1]
+        + alphas[2] * elements[2]
+        + alphas[3] * elements[3];
+    assert_eq!(expected, build_value(&alphas, &elements));
+}
+
+#[test]
+#[should_panic(expected = "number of alphas (4) does not match the number of elements (3)")]
+fn build_value_length_mismatch() {
+    let alphas = rand_array::<Felt, 4>();
+    build_value(&alphas, &[ONE, ONE, ONE]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds the expected value of a memory request independently of the main trace.
+fn build_expected_memory_request(
+    alphas: &[Felt],
+    op_label: u8,
+    ctx: u64,
+    clk: u64,
+    addr: Felt,
+    word: Word,
+) -> Felt {
+    alphas[0]
+        + alphas[1] * Felt::from(op_label)
+        + alphas[2] * Felt::new(ctx)
+        + alphas[3] * addr
+        + alphas[4] * Felt::new(clk)
+        + alphas[5] * word[0]
+        + alphas[6] * word[1]
+        + alphas[7] * word[2]
+        + alphas[8] * word[3]
+}
+
+/// A minimal main trace in which all cells are set to zero unless explicitly set otherwise.
+struct MainTraceFixture {
+    columns: Vec<Vec<Felt>>,
+}
+
+impl MainTraceFixture {
+    fn new(num_rows: usize) -> Self {
+        Self {
+            columns: vec![vec![ZERO; num_rows]; TRACE_WIDTH],
+        }
+    }
+
+    /// Sets the clock cycle and the execution context at the specified row.
+    fn set_system(&mut self, row: RowIndex, clk: Felt, ctx: Felt) {
+        self.columns[CLK_COL_IDX][row.as_usize()] = clk;
+        self.columns[CTX_COL_IDX][row.as_usize()] = ctx;
+    }
+
+    /// Sets the top stack elements at the specified row, starting from the top of the stack.
+    fn set_stack(&mut self, row: RowIndex, values: &[Felt]) {
+        for (i, &value) in values.iter().enumerate() {
+            self.columns[STACK_TRACE_OFFSET + i][row.as_usize()] = value;
+        }
+    }
+
+    /// Sets the op bits of the decoder at the specified row to encode `op_code`.
+    fn set_op_code(&mut self, row: RowIndex, op_code: u8) {
+        for i in 0..NUM_OP_BITS {
+            let bit = Felt::from((op_code >> i) & 1);
+            self.columns[DECODER_TRACE_OFFSET + OP_BITS_OFFSET + i][row.as_usize()] = bit;
+        }
+    }
+
+    /// Sets the decoder helper registers at the specified row.
+    fn set_helpers(&mut self, row: RowIndex, values: &[Felt]) {
+        for (i, &value) in values.iter().enumerate() {
+            self.columns[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + i][row.as_usize()] = value;
+        }
+    }
+
+    /// Sets the stack depth and the address of the top row of the overflow table at the specified
+    /// row, and sets the helper column so that the overflow flag is consistent with the depth.
+    fn set_overflow(&mut self, row: RowIndex, depth: u64, overflow_addr: u64) {
+        let h0 = if depth > 16 { Felt::new(depth - 16).inv() } else { ZERO };
+        self.columns[STACK_TRACE_OFFSET + B0_COL_IDX][row.as_usize()] = Felt::new(depth);
+        self.columns[STACK_TRACE_OFFSET + B1_COL_IDX][row.as_usize()] = Felt::new(overflow_addr);
+        self.columns[STACK_TRACE_OFFSET + H0_COL_IDX][row.as_usize()] = h0;
+    }
+
+    /// Sets the memory chiplet columns at the specified row to represent an access of `word` at
+    /// `addr`.
+    fn set_memory_access(
+        &mut self,
+        row: RowIndex,
+        is_read: bool,
+        ctx: Felt,
+        clk: Felt,
+        addr: Felt,
+        word: Word,
+    ) {
+        let row = row.as_usize();
+        let selectors = [ONE, ONE, ZERO, if is_read { ONE } else { ZERO }];
+        for (i, selector) in selectors.into_iter().enumerate() {
+            self.columns[CHIPLETS_OFFSET + i][row] = selector;
+        }
+        self.columns[MEMORY_CTX_COL_IDX][row] = ctx;
+        self.columns[MEMORY_CLK_COL_IDX][row] = clk;
+        self.columns[MEMORY_ADDR_COL_IDX][row] = addr;
+        for (i, value) in word.into_iter().enumerate() {
+            self.columns[MEMORY_V_COL_RANGE.start + i][row] = value;
+        }
+    }
+
+    /// Sets the decoder block address at the specified row.
+    fn set_block_addr(&mut self, row: RowIndex, addr: Felt) {
+        self.columns[DECODER_TRACE_OFFSET][row.as_usize()] = addr;
+    }
+
+    /// Sets the chiplet columns at the specified row to represent a kernel ROM row for the
+    /// procedure with the specified `root` at `addr`.
+    fn set_kernel_rom_row(&mut self, row: RowIndex, addr: Felt, root: Word) {
+        let row = row.as_usize();
+        for (i, selector) in ChipletLabel::KernelProcCall.selectors().into_iter().enumerate() {
+            self.columns[CHIPLETS_OFFSET + i][row] = selector;
+        }
+        self.columns[CHIPLETS_OFFSET + 5][row] = addr;
+        for (i, value) in root.into_iter().enumerate() {
+            self.columns[CHIPLETS_OFFSET + 6 + i][row] = value;
+        }
+    }
+
+    /// Sets the chiplet selectors at the specified row to represent a chiplets padding row.
+    fn set_chiplets_padding_row(&mut self, row: RowIndex) {
+        for i in 0..4 {
+            self.columns[CHIPLETS_OFFSET + i][row.as_usize()] = ONE;
+        }
+    }
+
+    fn build(self) -> MainTrace {
+        let num_rows = self.columns[0].len();
+        MainTrace::new(ColMatrix::new(self.columns), RowIndex::from(num_rows - 1))
+    }
+}
@@ -195,7 +195,12 @@ impl VmStateIterator {
         let range_table_len = range.get_number_range_checker_rows();
         chiplets.append_range_checks(range);
 
-        TraceLenSummary::new(clk.into(), range_table_len, ChipletsLengths::new(chiplets))
+        TraceLenSummary::new(
+            clk.into(),
+            range_table_len,
+            range.num_lookups(),
+            ChipletsLengths::new(chiplets),
+        )
     }
 }
 
@@ -5,7 +5,7 @@ use std::error::Error;
 
 use miden_air::RowIndex;
 use vm_core::{
-    mast::{DecoratorId, MastNodeId},
+    mast::{DecoratorId, MastForestError, MastNodeId},
     stack::MIN_STACK_DEPTH,
     utils::to_hex,
 };
@@ -64,6 +64,7 @@ pub enum ExecutionError {
     MastNodeNotFoundInForest {
         node_id: MastNodeId,
     },
+    MastForestLoadFailed(MastForestError),
     MastForestNotFound {
         root_digest: Digest,
     },
@@ -169,6 +170,7 @@ impl Display for ExecutionError {
             MastNodeNotFoundInForest { node_id } => {
                 write!(f, "Malformed MAST forest, node id {node_id} doesn't exist")
             },
+            MastForestLoadFailed(err) => write!(f, "Failed to load MAST forest into host: {err}"),
             MastForestNotFound { root_digest } => {
                 write!(
                     f,
@@ -25,9 +25,7 @@ pub use providers::{MemAdviceProvider, RecAdviceProvider};
 
 mod source;
 pub use source::AdviceSource;
-
-mod map;
-pub use map::AdviceMap;
+pub use vm_core::AdviceMap;
 
 // ADVICE PROVIDER
 // ================================================================================================
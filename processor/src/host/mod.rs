@@ -1,8 +1,8 @@
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 
 use vm_core::{
     crypto::{hash::RpoDigest, merkle::MerklePath},
-    mast::MastForest,
+    mast::{MastForest, MastForestError},
     AdviceInjector, DebugOptions, Word,
 };
 
@@ -112,6 +112,31 @@ pub trait Host {
         Ok(HostResponse::None)
     }
 
+    /// Handles a request for a key which is not present in the advice map.
+    ///
+    /// This is invoked by the VM when an advice injector fails with
+    /// [ExecutionError::AdviceMapKeyNotFound]. A host which can compute the missing values on
+    /// demand (e.g., by loading them lazily from a database) returns them here; the VM then inserts
+    /// them into the advice map via [Host::insert_advice_map_entry] and retries the injector.
+    ///
+    /// By default, `None` is returned and execution fails with the original error.
+    fn on_advice_key_miss(&mut self, _key: RpoDigest) -> Option<Vec<Felt>> {
+        None
+    }
+
+    /// Inserts the specified values into the advice map under the specified key.
+    ///
+    /// This is used by the VM to insert the values returned by [Host::on_advice_key_miss], and
+    /// thus must be implemented by hosts which override that method. The default implementation
+    /// returns [ExecutionError::AdviceMapKeyNotFound].
+    fn insert_advice_map_entry(
+        &mut self,
+        key: RpoDigest,
+        _values: Vec<Felt>,
+    ) -> Result<(), ExecutionError> {
+        Err(ExecutionError::AdviceMapKeyNotFound(key.into()))
+    }
+
     /// Handles the failure of the assertion instruction.
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         ExecutionError::FailedAssertion {
@@ -227,6 +252,18 @@ where
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
+
+    fn on_advice_key_miss(&mut self, key: RpoDigest) -> Option<Vec<Felt>> {
+        H::on_advice_key_miss(self, key)
+    }
+
+    fn insert_advice_map_entry(
+        &mut self,
+        key: RpoDigest,
+        values: Vec<Felt>,
+    ) -> Result<(), ExecutionError> {
+        H::insert_advice_map_entry(self, key, values)
+    }
 }
 
 // HOST RESPONSE
@@ -316,8 +353,36 @@ where
         }
     }
 
-    pub fn load_mast_forest(&mut self, mast_forest: Arc<MastForest>) {
-        self.store.insert(mast_forest)
+    /// Loads the specified MAST forest into the host, and merges its advice map into the advice
+    /// provider.
+    ///
+    /// Advice map entries which are already present in the advice provider with the same values
+    /// are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if a key of the forest's advice map is already present in the advice
+    /// provider with different values; in this case, the host is left unchanged.
+    pub fn load_mast_forest(&mut self, mast_forest: Arc<MastForest>) -> Result<(), ExecutionError> {
+        for (key, values) in mast_forest.advice_map().iter() {
+            if self
+                .adv_provider
+                .get_mapped_values(key)
+                .is_some_and(|existing| existing != values)
+            {
+                return Err(ExecutionError::MastForestLoadFailed(
+                    MastForestError::AdviceMapKeyCollisionOnMerge(*key),
+                ));
+            }
+        }
+
+        for (key, values) in mast_forest.advice_map().iter() {
+            if self.adv_provider.get_mapped_values(key).is_none() {
+                self.adv_provider.insert_into_map(key.into(), values.clone())?;
+            }
+        }
+        self.store.insert(mast_forest);
+
+        Ok(())
     }
 
     #[cfg(any(test, feature = "testing"))]
@@ -358,4 +423,12 @@ where
     fn get_mast_forest(&self, node_digest: &RpoDigest) -> Option<Arc<MastForest>> {
         self.store.get(node_digest)
     }
+
+    fn insert_advice_map_entry(
+        &mut self,
+        key: RpoDigest,
+        values: Vec<Felt>,
+    ) -> Result<(), ExecutionError> {
+        self.adv_provider.insert_into_map(key.into(), values)
+    }
 }
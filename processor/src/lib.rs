@@ -592,7 +592,19 @@ where
     fn execute_decorator(&mut self, decorator: &Decorator) -> Result<(), ExecutionError> {
         match decorator {
             Decorator::Advice(injector) => {
-                self.host.borrow_mut().set_advice(self, *injector)?;
+                let result = self.host.borrow_mut().set_advice(self, *injector);
+                if let Err(ExecutionError::AdviceMapKeyNotFound(key)) = result {
+                    // give the host a chance to provide the missing values before failing
+                    let values = self
+                        .host
+                        .borrow_mut()
+                        .on_advice_key_miss(key.into())
+                        .ok_or(ExecutionError::AdviceMapKeyNotFound(key))?;
+                    self.host.borrow_mut().insert_advice_map_entry(key.into(), values)?;
+                    self.host.borrow_mut().set_advice(self, *injector)?;
+                } else {
+                    result?;
+                }
             },
             Decorator::Debug(options) => {
                 if self.decoder.in_debug_mode() {
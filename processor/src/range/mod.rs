@@ -1,3 +1,5 @@
+#[cfg(feature = "checked_trace")]
+use alloc::vec;
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_air::RowIndex;
@@ -121,8 +123,13 @@ impl RangeChecker {
         assert!(trace_len + num_rand_rows <= target_len, "target trace length too small");
 
         // allocated memory for the trace; this memory is un-initialized but this is not a problem
-        // because we'll overwrite all values in it anyway.
+        // because we'll overwrite all values in it anyway, except for the random rows at the end.
+        // with the `checked_trace` feature enabled the trace is zero-initialized instead, so that
+        // no uninitialized memory is ever observed (e.g., when running under Miri or sanitizers).
+        #[cfg(not(feature = "checked_trace"))]
         let mut trace = unsafe { [uninit_vector(target_len), uninit_vector(target_len)] };
+        #[cfg(feature = "checked_trace")]
+        let mut trace = [vec![ZERO; target_len], vec![ZERO; target_len]];
 
         // determine the number of padding rows needed to get to target trace length and pad the
         // table with the required number of rows.
@@ -158,6 +165,12 @@ impl RangeChecker {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the total number of range check lookups requested by the VM, counting repeated
+    /// lookups of the same value separately.
+    pub fn num_lookups(&self) -> usize {
+        self.lookups.values().sum()
+    }
+
     /// Returns the number of rows needed to support all 16-bit lookups requested by the VM.
     pub fn get_number_range_checker_rows(&self) -> usize {
         // pad the trace length by one, to account for an extra row of the u16::MAX value at the end
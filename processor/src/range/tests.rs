@@ -16,6 +16,7 @@ fn range_checks() {
         // add the value to the range checker's trace
         checker.add_value(value.as_int() as u16);
     }
+    assert_eq!(checker.num_lookups(), values.len());
 
     let RangeCheckTrace { trace, aux_builder: _ } = checker.into_trace(64, 0);
     validate_trace(&trace, &values);
@@ -60,6 +61,24 @@ fn range_checks_rand() {
     validate_trace(&trace, &values);
 }
 
+/// Reads every cell of the trace, including the rows reserved for random values. This is only
+/// sound when the trace is zero-initialized, and is meant to be run under Miri.
+#[cfg(feature = "checked_trace")]
+#[test]
+fn range_checks_trace_fully_initialized() {
+    let mut checker = RangeChecker::new();
+    for value in [0, 1, 2, 3, 100] {
+        checker.add_value(value);
+    }
+
+    let num_rand_rows = 4;
+    let trace_len = (checker.trace_len() + num_rand_rows).next_power_of_two();
+    let RangeCheckTrace { trace, aux_builder: _ } = checker.into_trace(trace_len, num_rand_rows);
+    for column in trace.iter() {
+        assert!(column[column.len() - num_rand_rows..].iter().all(|&value| value == ZERO));
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
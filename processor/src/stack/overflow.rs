@@ -196,11 +196,20 @@ impl OverflowTableRow {
     pub fn new(clk: Felt, val: Felt, prev: Felt) -> Self {
         Self { val, clk, prev }
     }
+
+    /// Returns the components of this row as a tuple `(clk, val, prev)`, in the same order as
+    /// they are passed to [OverflowTableRow::new].
+    #[cfg(test)]
+    pub fn components(&self) -> (Felt, Felt, Felt) {
+        (self.clk, self.val, self.prev)
+    }
 }
 
 impl OverflowTableRow {
     /// Reduces this row to a single field element in the field specified by E. This requires
     /// at least 4 alpha values.
+    ///
+    /// The row is reduced as `alphas[0] + alphas[1] * clk + alphas[2] * val + alphas[3] * prev`.
     pub fn to_value<E: FieldElement<BaseField = Felt>>(&self, alphas: &[E]) -> E {
         alphas[0]
             + alphas[1].mul_base(self.clk)
@@ -391,6 +391,20 @@ fn generate_trace() {
     assert_eq!(read_helpers(&trace, 13), build_helpers(16, 0));
 }
 
+// OVERFLOW TABLE ROW TESTS
+// ================================================================================================
+
+#[test]
+fn overflow_table_row_components_and_value() {
+    let (clk, val, prev) = (Felt::new(7), Felt::new(42), Felt::new(3));
+    let row = OverflowTableRow::new(clk, val, prev);
+    assert_eq!(row.components(), (clk, val, prev));
+
+    let alphas = [Felt::new(2), Felt::new(3), Felt::new(5), Felt::new(11)];
+    let expected = Felt::new(2 + 3 * 7 + 5 * 42 + 11 * 3);
+    assert_eq!(row.to_value(&alphas), expected);
+}
+
 // HELPERS
 // ================================================================================================
 
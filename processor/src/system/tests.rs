@@ -1,4 +1,8 @@
-use crate::{DefaultHost, ExecutionOptions, Kernel, Operation, Process, StackInputs};
+use vm_core::{assert_matches, mast::MastForest, Program, ONE};
+
+use crate::{
+    DefaultHost, ExecutionError, ExecutionOptions, Kernel, Operation, Process, StackInputs,
+};
 
 // Check that process returns an error if a maximum number of cycles is exceeded.
 #[test]
@@ -16,3 +20,37 @@ fn cycles_num_exceeded() {
     }
     assert!(process.execute_op(Operation::Noop).is_err());
 }
+
+// Check that executing a program which never terminates fails once the configured maximum number
+// of cycles is exceeded.
+#[test]
+fn cycles_num_exceeded_by_infinite_loop() {
+    let program = {
+        let mut mast_forest = MastForest::new();
+        // the body of the loop always leaves ONE at the top of the stack
+        let body = mast_forest.add_block(vec![Operation::Pad, Operation::Incr], None).unwrap();
+        let loop_node = mast_forest.add_loop(body).unwrap();
+        mast_forest.make_root(loop_node);
+        Program::new(mast_forest.into(), loop_node)
+    };
+
+    let options = ExecutionOptions::default().with_max_cycles(1024).unwrap();
+    let mut process = Process::new(
+        Kernel::default(),
+        StackInputs::new(vec![ONE]).unwrap(),
+        DefaultHost::default(),
+        options,
+    );
+    let err = process.execute(&program).unwrap_err();
+    assert_matches!(err, ExecutionError::CycleLimitExceeded(1024));
+}
+
+#[test]
+fn max_cycles_validation() {
+    assert_eq!(ExecutionOptions::default().with_max_cycles(2048).unwrap().max_cycles(), 2048);
+
+    // the limit cannot be smaller than the minimum trace length or the expected number of cycles
+    assert!(ExecutionOptions::default().with_max_cycles(1).is_err());
+    let options = ExecutionOptions::new(None, 4096, false, false).unwrap();
+    assert!(options.with_max_cycles(2048).is_err());
+}
@@ -309,8 +309,12 @@ where
     );
 
     // Get the lengths of the traces: main, range, and chiplets
-    let trace_len_summary =
-        TraceLenSummary::new(clk.into(), range_table_len, ChipletsLengths::new(&chiplets));
+    let trace_len_summary = TraceLenSummary::new(
+        clk.into(),
+        range_table_len,
+        range.num_lookups(),
+        ChipletsLengths::new(&chiplets),
+    );
 
     // Combine all trace segments into the main trace
     let system_trace = system.into_trace(trace_len, NUM_RAND_ROWS);
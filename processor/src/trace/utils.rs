@@ -1,7 +1,10 @@
 use alloc::vec::Vec;
 use core::slice;
 
-use miden_air::{trace::main_trace::MainTrace, RowIndex};
+use miden_air::{
+    trace::{chiplets::hasher::HASH_CYCLE_LEN, main_trace::MainTrace},
+    RowIndex,
+};
 #[cfg(test)]
 use vm_core::{utils::ToElements, Operation};
 
@@ -78,12 +81,14 @@ impl<'a> TraceFragment<'a> {
 ///
 /// - `main_trace_len` contains the length of the main trace.
 /// - `range_trace_len` contains the length of the range checker trace.
+/// - `num_range_check_lookups` contains the total number of range check lookups.
 /// - `chiplets_trace_len` contains the trace lengths of the all chiplets (hash, bitwise, memory,
 ///   kernel ROM)
 #[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
 pub struct TraceLenSummary {
     main_trace_len: usize,
     range_trace_len: usize,
+    num_range_check_lookups: usize,
     chiplets_trace_len: ChipletsLengths,
 }
 
@@ -91,11 +96,13 @@ impl TraceLenSummary {
     pub fn new(
         main_trace_len: usize,
         range_trace_len: usize,
+        num_range_check_lookups: usize,
         chiplets_trace_len: ChipletsLengths,
     ) -> Self {
         TraceLenSummary {
             main_trace_len,
             range_trace_len,
+            num_range_check_lookups,
             chiplets_trace_len,
         }
     }
@@ -110,6 +117,12 @@ impl TraceLenSummary {
         self.range_trace_len
     }
 
+    /// Returns the total number of range check lookups, counting repeated lookups of the same
+    /// value separately.
+    pub fn num_range_check_lookups(&self) -> usize {
+        self.num_range_check_lookups
+    }
+
     /// Returns [ChipletsLengths] which contains trace lengths of all chilplets.
     pub fn chiplets_trace_len(&self) -> ChipletsLengths {
         self.chiplets_trace_len
@@ -173,6 +186,11 @@ impl ChipletsLengths {
         self.hash_chiplet_len
     }
 
+    /// Returns the number of hasher permutations recorded in the hash chiplet trace
+    pub fn num_hash_permutations(&self) -> usize {
+        self.hash_chiplet_len / HASH_CYCLE_LEN
+    }
+
     /// Returns the length of the bitwise trace
     pub fn bitwise_chiplet_len(&self) -> usize {
         self.bitwise_chiplet_len
@@ -33,7 +33,9 @@ mod gpu;
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofParams, ProvingOptions,
+};
 pub use processor::{
     crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
     StackInputs, StackOutputs, Word,
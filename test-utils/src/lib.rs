@@ -240,10 +240,10 @@ impl Test {
         let (program, kernel) = self.compile().expect("Failed to compile test source.");
         let mut host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
         if let Some(kernel) = kernel {
-            host.load_mast_forest(kernel.mast_forest().clone());
+            host.load_mast_forest(kernel.mast_forest().clone()).unwrap();
         }
         for library in &self.libraries {
-            host.load_mast_forest(library.mast_forest().clone());
+            host.load_mast_forest(library.mast_forest().clone()).unwrap();
         }
 
         // execute the test
@@ -338,10 +338,10 @@ impl Test {
         let (program, kernel) = self.compile().expect("Failed to compile test source.");
         let mut host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
         if let Some(kernel) = kernel {
-            host.load_mast_forest(kernel.mast_forest().clone());
+            host.load_mast_forest(kernel.mast_forest().clone())?;
         }
         for library in &self.libraries {
-            host.load_mast_forest(library.mast_forest().clone());
+            host.load_mast_forest(library.mast_forest().clone())?;
         }
         processor::execute(&program, self.stack_inputs.clone(), host, ExecutionOptions::default())
     }
@@ -354,10 +354,10 @@ impl Test {
         let (program, kernel) = self.compile().expect("Failed to compile test source.");
         let mut host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
         if let Some(kernel) = kernel {
-            host.load_mast_forest(kernel.mast_forest().clone());
+            host.load_mast_forest(kernel.mast_forest().clone())?;
         }
         for library in &self.libraries {
-            host.load_mast_forest(library.mast_forest().clone());
+            host.load_mast_forest(library.mast_forest().clone())?;
         }
 
         let mut process = Process::new(
@@ -378,10 +378,10 @@ impl Test {
         let (program, kernel) = self.compile().expect("Failed to compile test source.");
         let mut host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
         if let Some(kernel) = kernel {
-            host.load_mast_forest(kernel.mast_forest().clone());
+            host.load_mast_forest(kernel.mast_forest().clone()).unwrap();
         }
         for library in &self.libraries {
-            host.load_mast_forest(library.mast_forest().clone());
+            host.load_mast_forest(library.mast_forest().clone()).unwrap();
         }
         let (mut stack_outputs, proof) =
             prover::prove(&program, stack_inputs.clone(), host, ProvingOptions::default()).unwrap();
@@ -403,10 +403,10 @@ impl Test {
         let (program, kernel) = self.compile().expect("Failed to compile test source.");
         let mut host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
         if let Some(kernel) = kernel {
-            host.load_mast_forest(kernel.mast_forest().clone());
+            host.load_mast_forest(kernel.mast_forest().clone()).unwrap();
         }
         for library in &self.libraries {
-            host.load_mast_forest(library.mast_forest().clone());
+            host.load_mast_forest(library.mast_forest().clone()).unwrap();
         }
         processor::execute_iter(&program, self.stack_inputs.clone(), host)
     }
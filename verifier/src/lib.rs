@@ -8,7 +8,7 @@ extern crate std;
 use alloc::vec;
 use core::fmt;
 
-use air::{HashFunction, ProcessorAir, ProvingOptions, PublicInputs};
+use air::{ProcessorAir, ProvingOptions, PublicInputs};
 use vm_core::crypto::{
     hash::{Blake3_192, Blake3_256, Rpo256, Rpx256},
     random::{RpoRandomCoin, RpxRandomCoin, WinterRandomCoin},
@@ -21,7 +21,7 @@ pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, HashFunction, ProofParams};
 
 // VERIFIER
 // ================================================================================================
@@ -98,7 +98,7 @@ pub fn verify(
             )
         },
     }
-    .map_err(VerificationError::VerifierError)?;
+    .map_err(|source| VerificationError::VerifierError { hash_fn, security_level, source })?;
 
     Ok(security_level)
 }
@@ -109,7 +109,13 @@ pub fn verify(
 /// TODO: add docs
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VerificationError {
-    VerifierError(VerifierError),
+    /// The proof failed to verify; `hash_fn` and `security_level` are the hash function used to
+    /// generate the proof and the security level claimed by the proof.
+    VerifierError {
+        hash_fn: HashFunction,
+        security_level: u32,
+        source: VerifierError,
+    },
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
 }
@@ -118,7 +124,10 @@ impl fmt::Display for VerificationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use VerificationError::*;
         match self {
-            VerifierError(e) => write!(f, "{e}"),
+            VerifierError { hash_fn, security_level, source } => write!(
+                f,
+                "proof verification failed ({hash_fn:?}, claimed {security_level}-bit): {source}"
+            ),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
         }
@@ -127,3 +136,35 @@ impl fmt::Display for VerificationError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for VerificationError {}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::{HashFunction, VerificationError, VerifierError};
+
+    #[test]
+    fn verification_error_clone() {
+        let errors = [
+            VerificationError::VerifierError {
+                hash_fn: HashFunction::Blake3_192,
+                security_level: 96,
+                source: VerifierError::UnacceptableProofOptions,
+            },
+            VerificationError::VerifierError {
+                hash_fn: HashFunction::Rpo256,
+                security_level: 128,
+                source: VerifierError::ProofDeserializationError(String::from("invalid proof")),
+            },
+            VerificationError::InputNotFieldElement(u64::MAX),
+            VerificationError::OutputNotFieldElement(u64::MAX),
+        ];
+
+        for error in errors {
+            assert_eq!(error.clone(), error);
+        }
+    }
+}